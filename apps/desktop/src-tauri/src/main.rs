@@ -1,9 +1,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use devclean_core::{
-    ai_assess, build_delete_plan, evaluate_heuristic, get_cached_assessment, hash_file,
-    merge_with_ai, read_cache, scan_projects, set_cached_assessment, write_cache, DeleteEntry,
-    ProjectRecord, ScanProgress,
+    ai_assess, build_delete_plan, evaluate_heuristic, execute_prune, get_cached_assessment,
+    hash_file, merge_with_ai, read_cache, remove_path, scan_projects, set_cached_assessment,
+    write_cache, DeleteEntry, ProjectRecord, PruneOptions, ScanProgress,
 };
 use dirs::data_dir;
 use serde::{Deserialize, Serialize};
@@ -35,6 +35,8 @@ struct ScanRequest {
     scan_all: bool,
     ai_enabled: bool,
     scan_caches: bool,
+    #[serde(default)]
+    threads: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -72,13 +74,18 @@ struct DeleteRequest {
     deps_only: bool,
     dry_run: bool,
     quarantine: bool,
+    #[serde(default)]
+    threads: usize,
+    prune: Option<PruneOptions>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct DeleteItemPayload {
     path: String,
+    project_path: String,
     size_bytes: u64,
+    prune_bytes: Option<u64>,
     action: String,
     status: String,
     destination: Option<String>,
@@ -144,6 +151,7 @@ async fn scan_start(app: AppHandle, request: ScanRequest) -> Result<ScanComplete
     let app_handle = app.clone();
     let scan_all = request.scan_all;
     let scan_caches = request.scan_caches;
+    let threads = request.threads;
     let ai_enabled = request.ai_enabled;
     let root_clone = root.clone();
     let api_key = load_ai_key();
@@ -154,7 +162,7 @@ async fn scan_start(app: AppHandle, request: ScanRequest) -> Result<ScanComplete
     }
 
     let scan_result = tauri::async_runtime::spawn_blocking(move || {
-        scan_projects(&root_clone, scan_all, scan_caches, Some(|progress: ScanProgress| {
+        scan_projects(&root_clone, scan_all, scan_caches, threads, Some(|progress: ScanProgress| {
             let _ = app_handle.emit(
                 "scan.progress",
                 ScanProgressPayload {
@@ -191,12 +199,10 @@ async fn scan_start(app: AppHandle, request: ScanRequest) -> Result<ScanComplete
                         };
                     }
 
-                    let hash = PathBuf::from(&meta.package_json_path);
-                    let hash_value = if meta.package_json_path.is_empty() {
-                        None
-                    } else {
-                        hash_file(&hash)
-                    };
+                    let hash_value = meta
+                        .manifests
+                        .first()
+                        .and_then(|path| hash_file(&PathBuf::from(path)));
 
                     if let Some(hash_value) = hash_value.as_ref() {
                         if let Some(cached) = get_cached_assessment(&cache, &meta.id, hash_value) {
@@ -253,7 +259,7 @@ async fn scan_start(app: AppHandle, request: ScanRequest) -> Result<ScanComplete
     let cache_count = records.iter().filter(|item| item.meta.is_cache).count();
     let cache_bytes = records
         .iter()
-        .filter(|item| item.meta.is_cache)
+        .filter(|item| item.meta.is_cache && item.meta.parent_id.is_none())
         .map(|item| item.meta.size_bytes)
         .sum();
 
@@ -286,7 +292,14 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
         })
         .collect();
 
-    let plan = build_delete_plan(&entries, request.deps_only);
+    // Pruning deletes files in place, which quarantine could not undo, so
+    // caches are moved whole while quarantine is on.
+    let prune = request
+        .prune
+        .as_ref()
+        .filter(|_| !request.quarantine)
+        .map(|options| options.excluding(&entries));
+    let plan = build_delete_plan(&entries, request.deps_only, prune.as_ref(), request.threads);
     let action = if request.quarantine { "quarantine" } else { "delete" };
 
     if request.dry_run {
@@ -295,8 +308,10 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
             .into_iter()
             .map(|item| DeleteItemPayload {
                 path: item.path,
+                project_path: item.project_path,
                 size_bytes: item.size_bytes,
-                action: action.to_string(),
+                prune_bytes: item.prune_bytes,
+                action: item_action(item.prune_bytes, action),
                 status: "dry-run".to_string(),
                 destination: None,
                 original_path: None,
@@ -305,7 +320,7 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
 
         return Ok(DeleteResponsePayload {
             removed_count: 0,
-            reclaimed_bytes: plan.total_bytes,
+            reclaimed_bytes: plan.prune_total_bytes,
             items,
         });
     }
@@ -329,8 +344,10 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
         if !target.exists() {
             items.push(DeleteItemPayload {
                 path: item.path,
+                project_path: item.project_path,
                 size_bytes: item.size_bytes,
-                action: action.to_string(),
+                prune_bytes: item.prune_bytes,
+                action: item_action(item.prune_bytes, action),
                 status: "missing".to_string(),
                 destination: None,
                 original_path: None,
@@ -338,6 +355,27 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
             continue;
         }
 
+        if let (Some(options), Some(_)) = (&prune, item.prune_bytes) {
+            let status = match execute_prune(&target, options) {
+                Ok(summary) => {
+                    reclaimed_bytes = reclaimed_bytes.saturating_add(summary.reclaimed_bytes);
+                    "pruned".to_string()
+                }
+                Err(error) => format!("error: {error}"),
+            };
+            items.push(DeleteItemPayload {
+                path: item.path,
+                project_path: item.project_path,
+                size_bytes: item.size_bytes,
+                prune_bytes: item.prune_bytes,
+                action: item_action(item.prune_bytes, action),
+                status,
+                destination: None,
+                original_path: None,
+            });
+            continue;
+        }
+
         let result = if let Some(base) = &quarantine_root {
             let name = target
                 .file_name()
@@ -358,12 +396,8 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
             fs::rename(&target, &destination)
                 .map(|_| destination)
                 .map_err(|error| error.to_string())
-        } else if target.is_file() {
-            fs::remove_file(&target)
-                .map(|_| target.clone())
-                .map_err(|error| error.to_string())
         } else {
-            fs::remove_dir_all(&target)
+            remove_path(&target)
                 .map(|_| target.clone())
                 .map_err(|error| error.to_string())
         };
@@ -375,8 +409,10 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
                 let path = item.path;
                 items.push(DeleteItemPayload {
                     path: path.clone(),
+                    project_path: item.project_path,
                     size_bytes: item.size_bytes,
-                    action: action.to_string(),
+                    prune_bytes: item.prune_bytes,
+                    action: item_action(item.prune_bytes, action),
                     status: know_action_status(request.quarantine),
                     destination: if request.quarantine {
                         Some(destination.to_string_lossy().to_string())
@@ -393,8 +429,10 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
             Err(error) => {
                 items.push(DeleteItemPayload {
                     path: item.path,
+                    project_path: item.project_path,
                     size_bytes: item.size_bytes,
-                    action: action.to_string(),
+                    prune_bytes: item.prune_bytes,
+                    action: item_action(item.prune_bytes, action),
                     status: format!("error: {error}"),
                     destination: None,
                     original_path: None,
@@ -410,6 +448,14 @@ async fn delete_execute(_app: AppHandle, request: DeleteRequest) -> Result<Delet
     })
 }
 
+fn item_action(prune_bytes: Option<u64>, action: &str) -> String {
+    if prune_bytes.is_some() {
+        "prune".to_string()
+    } else {
+        action.to_string()
+    }
+}
+
 fn know_action_status(quarantine: bool) -> String {
     if quarantine {
         "moved".to_string()
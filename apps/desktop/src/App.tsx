@@ -5,6 +5,7 @@ import {homeDir, desktopDir, documentDir, downloadDir} from '@tauri-apps/api/pat
 import {open, save} from '@tauri-apps/plugin-dialog';
 import {check as checkForUpdates} from '@tauri-apps/plugin-updater';
 import type {
+	DeleteItem,
 	DeleteRequest,
 	DeleteResponse,
 	FeedbackRequest,
@@ -53,6 +54,61 @@ const tailPath = (value: string, segments = 4) => {
 	return `.../${tail}`;
 };
 
+const artifactSummary = (project: ProjectRecord) => {
+	if (project.artifacts.length === 0) return 'No regenerable folders found';
+	const root = project.path.replace(/\\/g, '/').replace(/\/$/, '');
+	return project.artifacts
+		.map(artifact => {
+			const normalized = artifact.path.replace(/\\/g, '/');
+			if (artifact.outsideProject) return `${normalized} (outside project)`;
+			return normalized.startsWith(`${root}/`) ? normalized.slice(root.length + 1) : normalized;
+		})
+		.join(' + ');
+};
+
+// Keeps nested caches directly below the entry that contains them, preserving
+// the current sort among siblings.
+const nestUnderParents = (list: ProjectRecord[]) => {
+	const ids = new Set(list.map(project => project.id));
+	const children = new Map<string, ProjectRecord[]>();
+	const roots: ProjectRecord[] = [];
+	for (const project of list) {
+		if (project.parentId && ids.has(project.parentId)) {
+			children.set(project.parentId, [...(children.get(project.parentId) ?? []), project]);
+		} else {
+			roots.push(project);
+		}
+	}
+
+	const ordered: ProjectRecord[] = [];
+	const depths = new Map<string, number>();
+	const visit = (project: ProjectRecord, depth: number) => {
+		ordered.push(project);
+		depths.set(project.id, depth);
+		for (const child of children.get(project.id) ?? []) {
+			visit(child, depth + 1);
+		}
+	};
+	roots.forEach(project => visit(project, 0));
+	return {ordered, depths};
+};
+
+// Groups plan items under the project they belong to, in plan order.
+const groupByProject = (items: DeleteItem[]) => {
+	const groups = new Map<string, DeleteItem[]>();
+	for (const item of items) {
+		groups.set(item.projectPath, [...(groups.get(item.projectPath) ?? []), item]);
+	}
+	return [...groups.entries()].map(([projectPath, groupItems]) => ({projectPath, items: groupItems}));
+};
+
+const relativeTo = (root: string, value: string) => {
+	const base = root.replace(/\\/g, '/').replace(/\/$/, '');
+	const normalized = value.replace(/\\/g, '/');
+	if (normalized === base) return '(entire folder)';
+	return normalized.startsWith(`${base}/`) ? normalized.slice(base.length + 1) : tailPath(value, 4);
+};
+
 const InfoTip = ({text}: {text: string}) => (
 	<span className="info-tip" title={text} aria-label={text}>
 		i
@@ -113,6 +169,7 @@ export default function App() {
 	const [scanAll, setScanAll] = useState(false);
 	const [aiEnabled, setAiEnabled] = useState(false);
 	const [scanCaches, setScanCaches] = useState(false);
+	const [walkThreads, setWalkThreads] = useState(0);
 	const [quickPaths, setQuickPaths] = useState<{label: string; path: string}[]>([]);
 	const [diskRoot, setDiskRoot] = useState<string | null>(null);
 	const [scanKey, setScanKey] = useState('');
@@ -122,6 +179,8 @@ export default function App() {
 	const [deleteDepsOnly, setDeleteDepsOnly] = useState(true);
 	const [deleteDryRun, setDeleteDryRun] = useState(true);
 	const [deleteQuarantine, setDeleteQuarantine] = useState(true);
+	const [deletePrune, setDeletePrune] = useState(false);
+	const [pruneAgeDays, setPruneAgeDays] = useState(30);
 	const [deletePlan, setDeletePlan] = useState<DeleteResponse | null>(null);
 	const [deleteBusy, setDeleteBusy] = useState(false);
 	const [deleteError, setDeleteError] = useState<string | null>(null);
@@ -286,6 +345,7 @@ export default function App() {
 			scanAll,
 			aiEnabled,
 			scanCaches,
+			threads: walkThreads,
 		};
 		const nextKey = `${resolvedRoot}|${scanAll ? 'all' : 'root'}|${scanCaches ? 'caches' : 'nocache'}`;
 		setScanKey(nextKey);
@@ -320,7 +380,7 @@ export default function App() {
 		setLastIndex(null);
 	}, [searchQuery, riskFilter, sortKey, sortDir]);
 
-	const visibleProjects = useMemo(() => {
+	const {ordered: visibleProjects, depths: rowDepths} = useMemo(() => {
 		const query = searchQuery.trim().toLowerCase();
 		let list = projects;
 
@@ -365,7 +425,7 @@ export default function App() {
 			return sortDir === 'asc' ? result : -result;
 		});
 
-		return sorted;
+		return nestUnderParents(sorted);
 	}, [projects, riskFilter, searchQuery, sortKey, sortDir]);
 
 	const progressPercent = useMemo(() => {
@@ -385,9 +445,16 @@ export default function App() {
 	}, [progress, elapsedMs, etaMs]);
 
 	const selectedTotal = useMemo(() => {
+		const byId = new Map(projects.map(project => [project.id, project]));
+		const hasSelectedAncestor = (project: ProjectRecord) => {
+			for (let parentId = project.parentId; parentId; parentId = byId.get(parentId)?.parentId) {
+				if (selectedIds.has(parentId)) return true;
+			}
+			return false;
+		};
 		let total = 0;
 		for (const project of projects) {
-			if (selectedIds.has(project.id)) {
+			if (selectedIds.has(project.id) && !hasSelectedAncestor(project)) {
 				total += project.sizeBytes;
 			}
 		}
@@ -395,7 +462,9 @@ export default function App() {
 	}, [projects, selectedIds]);
 
 	const reclaimableAfterDeletes = useMemo(() => {
-		const totalBytes = projects.reduce((sum, project) => sum + project.sizeBytes, 0);
+		const totalBytes = projects
+			.filter(project => !project.parentId)
+			.reduce((sum, project) => sum + project.sizeBytes, 0);
 		return formatBytes(Math.max(totalBytes - reclaimedBytes, 0));
 	}, [projects, reclaimedBytes]);
 
@@ -429,7 +498,7 @@ export default function App() {
 					id: project.id,
 					name: project.name,
 					path: project.path,
-					target: 'node_modules + .cache (if present)',
+					target: artifactSummary(project),
 				};
 			}
 			return {
@@ -493,6 +562,9 @@ export default function App() {
 		}
 	};
 
+	// Pruned files cannot be restored from quarantine, so pruning needs it off.
+	const pruneEnabled = deletePrune && !deleteQuarantine;
+
 	const buildDeleteRequest = (dryRunOverride?: boolean): DeleteRequest => ({
 		entries: selectedProjects.map(project => ({
 			path: project.path,
@@ -501,6 +573,13 @@ export default function App() {
 		depsOnly: deleteDepsOnly,
 		dryRun: dryRunOverride ?? deleteDryRun,
 		quarantine: deleteQuarantine,
+		threads: walkThreads,
+		prune: pruneEnabled
+			? {
+					maxAgeDays: pruneAgeDays,
+					projectPaths: projects.filter(project => !project.isCache).map(project => project.path),
+				}
+			: undefined,
 	});
 
 	const refreshPlan = async () => {
@@ -525,7 +604,7 @@ export default function App() {
 	useEffect(() => {
 		if (!showReview) return;
 		void refreshPlan();
-	}, [showReview, deleteDepsOnly, deleteQuarantine, selectedProjects]);
+	}, [showReview, deleteDepsOnly, deleteQuarantine, deletePrune, pruneAgeDays, selectedProjects, projects, walkThreads]);
 
 	const onExecuteDelete = async () => {
 		if (selectedProjects.length === 0) return;
@@ -566,11 +645,12 @@ export default function App() {
 			format === 'json'
 				? JSON.stringify(deletePlan.items, null, 2)
 				: [
-						'path,sizeBytes,action,status,destination',
+						'path,sizeBytes,pruneBytes,action,status,destination',
 						...deletePlan.items.map(item =>
 							[
 								item.path.replace(/"/g, '""'),
 								item.sizeBytes,
+								item.pruneBytes ?? '',
 								item.action,
 								item.status,
 								item.destination ?? '',
@@ -842,6 +922,16 @@ export default function App() {
 						/>
 						<span>Caches</span>
 					</label>
+					<label className="toggle">
+						<span>Threads</span>
+						<input
+							type="number"
+							min={0}
+							value={walkThreads}
+							onChange={event => setWalkThreads(Math.max(0, Math.floor(Number(event.target.value) || 0)))}
+						/>
+						<InfoTip text="Folders walked in parallel while scanning and sizing deletes. 0 uses one per CPU core." />
+					</label>
 				</div>
 				{aiKeyStatus ? (
 					<div className="ai-status">
@@ -942,7 +1032,12 @@ export default function App() {
 									className={`row ${isSelected ? 'selected' : ''}`}
 									onClick={event => onRowClick(visibleProjects, index, project.id, event)}
 								>
-									<span>{project.name}</span>
+									<span
+										className={project.parentId ? 'nested' : undefined}
+										style={{paddingLeft: `${(rowDepths.get(project.id) ?? 0) * 16}px`}}
+									>
+										{project.name}
+									</span>
 									<span className={`risk ${riskClass}`}>
 										{riskLabel}
 									</span>
@@ -965,7 +1060,11 @@ export default function App() {
 								<p><strong>Path:</strong> {selectedProject.path}</p>
 								<p><strong>Size:</strong> {formatBytes(selectedProject.sizeBytes)}</p>
 								<p><strong>Modified:</strong> {selectedProject.lastModifiedDays}d</p>
+								<p><strong>Ecosystem:</strong> {selectedProject.ecosystem ?? '--'}</p>
 								<p><strong>Deps:</strong> {selectedProject.dependencyCount}</p>
+								{selectedProject.cacheKind ? (
+									<p><strong>Cache kind:</strong> {selectedProject.cacheKind}</p>
+								) : null}
 								<p>
 									<strong>Risk:</strong>{' '}
 									{selectedProject.risk?.className ?? 'Unknown'} ({selectedProject.risk?.score ?? '--'})
@@ -975,8 +1074,10 @@ export default function App() {
 									<strong>Flags:</strong>{' '}
 									{selectedProject.hasGit ? 'git ' : ''}
 									{selectedProject.hasEnvFile ? '.env ' : ''}
+									{selectedProject.hasStateFile ? 'state ' : ''}
 									{selectedProject.hasStartupKeyword ? 'startup ' : ''}
-									{selectedProject.isCache ? 'cache' : ''}
+									{selectedProject.isCache ? 'cache ' : ''}
+									{selectedProject.inUse === false ? 'unused' : ''}
 								</p>
 								<div className="feedback">
 									<span>Was this safe?</span>
@@ -1044,7 +1145,7 @@ export default function App() {
 									/>
 									<span className="toggle-label">
 										Delete entire project
-										<InfoTip text="On: removes the project folder. Off: only removes node_modules/.cache inside each project." />
+										<InfoTip text="On: removes the project folder. Off: only removes regenerable folders (node_modules, target, ...) inside each project." />
 									</span>
 								</label>
 								<label className="toggle">
@@ -1058,6 +1159,26 @@ export default function App() {
 										<InfoTip text="On: move items into a local quarantine folder. Off: delete immediately." />
 									</span>
 								</label>
+								<label className="toggle">
+									<input
+										type="checkbox"
+										checked={pruneEnabled}
+										disabled={deleteQuarantine}
+										onChange={event => setDeletePrune(event.target.checked)}
+									/>
+									<span className="toggle-label">
+										Prune caches older than
+										<input
+											type="number"
+											min={0}
+											value={pruneAgeDays}
+											disabled={!pruneEnabled}
+											onChange={event => setPruneAgeDays(Math.max(0, Number(event.target.value) || 0))}
+										/>
+										days
+										<InfoTip text="On: npm's cache only loses entries unused for this many days and the pnpm store only loses packages no remaining scanned project uses. Off: caches are removed whole. Unavailable with quarantine on, since pruned files cannot be restored." />
+									</span>
+								</label>
 								<label className="toggle">
 									<input
 										type="checkbox"
@@ -1134,15 +1255,33 @@ export default function App() {
 										<span>Action</span>
 										<span>Status</span>
 									</div>
-									{deletePlan.items.map(item => (
-										<div key={item.path} className="review-row">
-											<span>{tailPath(item.path, 4)}</span>
-											<span>{formatBytes(item.sizeBytes)}</span>
-											<span className="muted">{item.action}</span>
-											<span className={item.status.startsWith('error') ? 'status error' : 'status'}>
-												{item.status}
-											</span>
-										</div>
+									{groupByProject(deletePlan.items).map(group => (
+										<React.Fragment key={group.projectPath}>
+											<div className="review-row group">
+												<span title={group.projectPath}>{tailPath(group.projectPath, 4)}</span>
+												<span>
+													{formatBytes(
+														group.items.reduce((total, item) => total + (item.pruneBytes ?? item.sizeBytes), 0),
+													)}
+												</span>
+												<span />
+												<span />
+											</div>
+											{group.items.map(item => (
+												<div key={item.path} className="review-row nested">
+													<span title={item.path}>{relativeTo(group.projectPath, item.path)}</span>
+													<span>
+														{item.pruneBytes != null
+															? `${formatBytes(item.pruneBytes)} of ${formatBytes(item.sizeBytes)}`
+															: formatBytes(item.sizeBytes)}
+													</span>
+													<span className="muted">{item.action}</span>
+													<span className={item.status.startsWith('error') ? 'status error' : 'status'}>
+														{item.status}
+													</span>
+												</div>
+											))}
+										</React.Fragment>
 									))}
 								</div>
 							) : (
@@ -27,7 +27,6 @@ struct GeminiPart {
 #[derive(Debug, Deserialize)]
 struct AiPayload {
     score: u8,
-    class_name: Option<String>,
     reasons: Vec<String>,
 }
 
@@ -76,11 +75,15 @@ pub fn ai_assess(meta: &ProjectMeta, api_key: &str, model: &str) -> Result<RiskA
         "project": {
             "name": meta.name,
             "path": meta.path,
+            "ecosystem": meta.ecosystem,
             "dependencyCount": meta.dependency_count,
             "hasGit": meta.has_git,
             "hasEnvFile": meta.has_env_file,
+            "hasStateFile": meta.has_state_file,
             "hasStartupKeyword": meta.has_startup_keyword,
             "lastModifiedDays": meta.last_modified_days,
+            "cacheKind": meta.cache_kind,
+            "inUse": meta.in_use,
             "sizeBytes": meta.size_bytes
         }
     });
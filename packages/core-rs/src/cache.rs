@@ -75,10 +75,7 @@ pub fn write_cache(root: &Path, cache: &CacheFile) -> std::io::Result<()> {
         return fs::write(fallback, data);
     }
 
-    Err(io::Error::new(
-        io::ErrorKind::Other,
-        "Unable to write cache file",
-    ))
+    Err(io::Error::other("Unable to write cache file"))
 }
 
 pub fn get_cached_assessment(cache: &CacheFile, key: &str, hash: &str) -> Option<RiskAssessment> {
@@ -0,0 +1,226 @@
+use super::node::{parse_version, range_matches};
+use super::{child_dirs, env_or_home, env_path, file_name, CacheCandidate};
+use crate::types::CacheKind;
+use dirs::cache_dir;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEPENDENCY_KEYS: &[&str] = &["dependencies", "devDependencies", "optionalDependencies"];
+const PLAYWRIGHT_PACKAGES: &[&str] = &["playwright", "playwright-core", "@playwright/test"];
+const PUPPETEER_PACKAGES: &[&str] = &["puppeteer", "puppeteer-core"];
+const PUPPETEER_REVISIONS: &[&str] = &["lib", "cjs", "puppeteer", "revisions.js"];
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn cache_path(variable: &str, name: &str) -> Option<PathBuf> {
+    env_path(variable).or_else(|| cache_dir().map(|path| path.join(name)))
+}
+
+fn declares_any(project_dirs: &[PathBuf], packages: &[&str]) -> bool {
+    project_dirs.iter().any(|dir| {
+        let pkg = match read_json(&dir.join("package.json")) {
+            Some(pkg) => pkg,
+            None => return false,
+        };
+        DEPENDENCY_KEYS
+            .iter()
+            .filter_map(|key| pkg.get(key))
+            .any(|deps| packages.iter().any(|name| deps.get(name).is_some()))
+    })
+}
+
+// The installed version wins over the declared range when node_modules exists.
+fn package_pins(project_dirs: &[PathBuf], package: &str) -> Vec<String> {
+    let mut pins = Vec::new();
+    for dir in project_dirs {
+        let installed = read_json(&dir.join("node_modules").join(package).join("package.json"))
+            .and_then(|pkg| pkg.get("version")?.as_str().map(str::to_string));
+        if let Some(version) = installed {
+            pins.push(version);
+            continue;
+        }
+        let pkg = match read_json(&dir.join("package.json")) {
+            Some(pkg) => pkg,
+            None => continue,
+        };
+        pins.extend(
+            DEPENDENCY_KEYS
+                .iter()
+                .filter_map(|key| pkg.get(key)?.get(package)?.as_str())
+                .map(str::to_string),
+        );
+    }
+    pins
+}
+
+fn candidate(path: PathBuf, label: &str, in_use: Option<bool>) -> CacheCandidate {
+    CacheCandidate {
+        label: format!("{label} - {}", file_name(&path)),
+        path,
+        expand_children: false,
+        kind: Some(CacheKind::Download),
+        in_use,
+    }
+}
+
+// Playwright names browser folders after the revisions pinned in
+// playwright-core's browsers.json, e.g. "chromium-1091".
+fn playwright_builds(project_dirs: &[PathBuf]) -> Vec<String> {
+    let mut builds = Vec::new();
+    for dir in project_dirs {
+        let path = dir
+            .join("node_modules")
+            .join("playwright-core")
+            .join("browsers.json");
+        let browsers = match read_json(&path) {
+            Some(data) => data,
+            None => continue,
+        };
+        for browser in browsers
+            .get("browsers")
+            .and_then(|value| value.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let name = browser.get("name").and_then(|value| value.as_str());
+            let revision = browser.get("revision").and_then(|value| value.as_str());
+            if let (Some(name), Some(revision)) = (name, revision) {
+                builds.push(format!("{}-{revision}", name.replace('-', "_")));
+            }
+        }
+    }
+    builds
+}
+
+fn playwright_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let root = match cache_path("PLAYWRIGHT_BROWSERS_PATH", "ms-playwright") {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+    let builds = playwright_builds(project_dirs);
+    let known = !builds.is_empty() || !declares_any(project_dirs, PLAYWRIGHT_PACKAGES);
+    child_dirs(&root)
+        .into_iter()
+        .filter(|path| !file_name(path).starts_with('.'))
+        .map(|path| {
+            let in_use = known.then(|| builds.contains(&file_name(&path)));
+            candidate(path, "Playwright browser", in_use)
+        })
+        .collect()
+}
+
+fn quoted_versions(data: &str) -> Vec<String> {
+    data.split(['\'', '"'])
+        .skip(1)
+        .step_by(2)
+        .filter(|value| value.contains('.') && parse_version(value).is_some())
+        .map(str::to_string)
+        .collect()
+}
+
+// Puppeteer keeps browser/platform-version folders, e.g.
+// chrome/linux-121.0.6167.85, under ~/.cache/puppeteer on every platform.
+fn puppeteer_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let root = match env_or_home("PUPPETEER_CACHE_DIR", &[".cache", "puppeteer"]) {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+    let mut builds = Vec::new();
+    for dir in project_dirs {
+        for package in PUPPETEER_PACKAGES {
+            let path = PUPPETEER_REVISIONS
+                .iter()
+                .fold(dir.join("node_modules").join(package), |path, part| {
+                    path.join(part)
+                });
+            if let Ok(data) = fs::read_to_string(path) {
+                builds.extend(quoted_versions(&data));
+            }
+        }
+    }
+    let known = !builds.is_empty() || !declares_any(project_dirs, PUPPETEER_PACKAGES);
+
+    let mut candidates = Vec::new();
+    for browser in child_dirs(&root) {
+        let label = format!("Puppeteer {}", file_name(&browser));
+        for path in child_dirs(&browser) {
+            let name = file_name(&path);
+            let version = name.split_once('-').map(|(_, version)| version);
+            let in_use = known.then(|| {
+                version.is_some_and(|version| builds.iter().any(|build| build == version))
+            });
+            candidates.push(candidate(path, &label, in_use));
+        }
+    }
+    candidates
+}
+
+fn version_matches(version: &str, pins: &[String]) -> bool {
+    match parse_version(version) {
+        Some(version) => pins.iter().any(|pin| range_matches(version, pin)),
+        None => false,
+    }
+}
+
+fn cypress_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let root = match cache_path("CYPRESS_CACHE_FOLDER", "Cypress") {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+    let pins = package_pins(project_dirs, "cypress");
+    child_dirs(&root)
+        .into_iter()
+        .filter(|path| parse_version(&file_name(path)).is_some())
+        .map(|path| {
+            let in_use = version_matches(&file_name(&path), &pins);
+            candidate(path, "Cypress binary", Some(in_use))
+        })
+        .collect()
+}
+
+// @electron/get stores each download in a folder named after a hash of its
+// URL, so the version is read from the archive name inside.
+fn electron_version(dir: &Path) -> Option<String> {
+    fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .find_map(|name| {
+            let rest = name.strip_prefix("electron-v")?;
+            rest.split('-').next().map(str::to_string)
+        })
+}
+
+fn electron_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let root = match cache_path("ELECTRON_CACHE", "electron") {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+    let pins = package_pins(project_dirs, "electron");
+    child_dirs(&root)
+        .into_iter()
+        .map(|path| {
+            let version = electron_version(&path);
+            CacheCandidate {
+                label: match &version {
+                    Some(version) => format!("Electron binary - v{version}"),
+                    None => format!("Electron binary - {}", file_name(&path)),
+                },
+                in_use: version.map(|version| version_matches(&version, &pins)),
+                path,
+                expand_children: false,
+                kind: Some(CacheKind::Download),
+            }
+        })
+        .collect()
+}
+
+pub(super) fn candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let mut candidates = playwright_candidates(project_dirs);
+    candidates.extend(puppeteer_candidates(project_dirs));
+    candidates.extend(cypress_candidates(project_dirs));
+    candidates.extend(electron_candidates(project_dirs));
+    candidates
+}
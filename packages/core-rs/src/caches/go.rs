@@ -0,0 +1,47 @@
+use super::{env_path, CacheCandidate};
+use crate::types::CacheKind;
+use dirs::{cache_dir, home_dir};
+use std::env;
+use std::path::PathBuf;
+
+fn module_cache() -> Option<PathBuf> {
+    if let Some(path) = env_path("GOMODCACHE") {
+        return Some(path);
+    }
+    let gopath = env::var_os("GOPATH")
+        .and_then(|value| env::split_paths(&value).next())
+        .filter(|path| !path.as_os_str().is_empty())
+        .or_else(|| home_dir().map(|path| path.join("go")))?;
+    Some(gopath.join("pkg").join("mod"))
+}
+
+fn build_cache() -> Option<PathBuf> {
+    match env_path("GOCACHE") {
+        Some(path) if path.as_os_str() == "off" => None,
+        Some(path) => Some(path),
+        None => cache_dir().map(|path| path.join("go-build")),
+    }
+}
+
+pub(super) fn candidates() -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+    if let Some(path) = module_cache() {
+        candidates.push(CacheCandidate {
+            path,
+            label: "Go module cache".to_string(),
+            expand_children: false,
+            kind: Some(CacheKind::Download),
+            in_use: None,
+        });
+    }
+    if let Some(path) = build_cache() {
+        candidates.push(CacheCandidate {
+            path,
+            label: "Go build cache".to_string(),
+            expand_children: false,
+            kind: Some(CacheKind::Derived),
+            in_use: None,
+        });
+    }
+    candidates
+}
@@ -0,0 +1,134 @@
+use super::{child_dirs, env_or_home, file_name, CacheCandidate};
+use crate::detect::{strip_xml_comments, xml_text};
+use crate::types::CacheKind;
+use dirs::home_dir;
+use std::fs;
+use std::path::PathBuf;
+
+const WRAPPER_PROPERTIES: &[&str] = &["gradle", "wrapper", "gradle-wrapper.properties"];
+
+fn is_version(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_digit()) && name.contains('.')
+}
+
+// "gradle-8.5-bin.zip" and "gradle-8.5-all" both name Gradle 8.5.
+fn distribution_version(name: &str) -> Option<String> {
+    let rest = name.strip_prefix("gradle-")?;
+    let rest = rest.strip_suffix(".zip").unwrap_or(rest);
+    let version = rest
+        .strip_suffix("-bin")
+        .or_else(|| rest.strip_suffix("-all"))
+        .unwrap_or(rest);
+    if is_version(version) {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+fn wrapper_versions(project_dirs: &[PathBuf]) -> Vec<String> {
+    let mut versions = Vec::new();
+    for dir in project_dirs {
+        let path = WRAPPER_PROPERTIES
+            .iter()
+            .fold(dir.to_path_buf(), |path, part| path.join(part));
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(_) => continue,
+        };
+        let version = data
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("distributionUrl"))
+            .filter_map(|value| value.rsplit('/').next())
+            .find_map(distribution_version);
+        if let Some(version) = version {
+            if !versions.contains(&version) {
+                versions.push(version);
+            }
+        }
+    }
+    versions
+}
+
+fn maven_repository() -> Option<PathBuf> {
+    let m2 = home_dir()?.join(".m2");
+    let configured = fs::read_to_string(m2.join("settings.xml"))
+        .ok()
+        .and_then(|data| xml_text(&strip_xml_comments(&data), "localRepository"))
+        .filter(|value| !value.contains("${"))
+        .map(PathBuf::from)
+        .filter(|path| path.is_dir());
+    Some(configured.unwrap_or_else(|| m2.join("repository")))
+}
+
+fn versioned(path: PathBuf, label: &str, kind: CacheKind, versions: &[String]) -> CacheCandidate {
+    let name = file_name(&path);
+    let version = distribution_version(&name).unwrap_or_else(|| name.clone());
+    CacheCandidate {
+        label: format!("{label} - {name}"),
+        in_use: Some(versions.contains(&version)),
+        path,
+        expand_children: false,
+        kind: Some(kind),
+    }
+}
+
+pub(super) fn candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+
+    if let Some(path) = maven_repository() {
+        candidates.push(CacheCandidate {
+            path,
+            label: "Maven repository".to_string(),
+            expand_children: false,
+            kind: Some(CacheKind::Download),
+            in_use: None,
+        });
+    }
+
+    let gradle_home = match env_or_home("GRADLE_USER_HOME", &[".gradle"]) {
+        Some(path) => path,
+        None => return candidates,
+    };
+    let versions = wrapper_versions(project_dirs);
+
+    // caches/ mixes per-version folders with shared ones such as modules-2,
+    // so each child is offered on its own instead of the whole folder.
+    for dir in child_dirs(&gradle_home.join("caches")) {
+        if is_version(&file_name(&dir)) {
+            candidates.push(versioned(
+                dir,
+                "Gradle caches",
+                CacheKind::Derived,
+                &versions,
+            ));
+        } else {
+            let label = format!("Gradle shared caches - {}", file_name(&dir));
+            candidates.push(CacheCandidate {
+                path: dir,
+                label,
+                expand_children: false,
+                kind: Some(CacheKind::Download),
+                in_use: None,
+            });
+        }
+    }
+    for dir in child_dirs(&gradle_home.join("wrapper").join("dists")) {
+        candidates.push(versioned(
+            dir,
+            "Gradle distribution",
+            CacheKind::Toolchain,
+            &versions,
+        ));
+    }
+    for dir in child_dirs(&gradle_home.join("daemon")) {
+        candidates.push(versioned(
+            dir,
+            "Gradle daemon",
+            CacheKind::Derived,
+            &versions,
+        ));
+    }
+
+    candidates
+}
@@ -0,0 +1,74 @@
+mod browsers;
+mod go;
+mod jvm;
+mod node;
+mod python;
+mod rust;
+
+use crate::types::CacheKind;
+use dirs::home_dir;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) struct CacheCandidate {
+    pub path: PathBuf,
+    pub label: String,
+    pub expand_children: bool,
+    pub kind: Option<CacheKind>,
+    pub in_use: Option<bool>,
+}
+
+/// Candidates for per-tool caches and toolchains. `project_dirs` holds the
+/// scanned projects so that pinned versions can be told apart from unused ones.
+pub(crate) fn tool_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+    candidates.extend(rust::candidates(project_dirs));
+    candidates.extend(python::candidates());
+    candidates.extend(jvm::candidates(project_dirs));
+    candidates.extend(go::candidates());
+    candidates.extend(node::candidates(project_dirs));
+    candidates.extend(browsers::candidates(project_dirs));
+    candidates
+}
+
+/// Appends the folder name, the label style used by the candidates that
+/// predate per-tool labels ("npm cache - .npm").
+pub(crate) fn with_folder_label(candidate: CacheCandidate) -> CacheCandidate {
+    let label = format!("{} - {}", candidate.label, file_name(&candidate.path));
+    CacheCandidate { label, ..candidate }
+}
+
+pub(crate) fn env_path(variable: &str) -> Option<PathBuf> {
+    env::var_os(variable)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+pub(crate) fn env_or_home(variable: &str, default: &[&str]) -> Option<PathBuf> {
+    if let Some(path) = env_path(variable) {
+        return Some(path);
+    }
+    let home = home_dir()?;
+    Some(default.iter().fold(home, |path, part| path.join(part)))
+}
+
+pub(crate) fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+pub(crate) fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|entry| entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false))
+            .map(|entry| entry.path())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    dirs.sort();
+    dirs
+}
@@ -0,0 +1,301 @@
+use super::{child_dirs, env_or_home, env_path, file_name, CacheCandidate};
+use crate::types::CacheKind;
+use dirs::{cache_dir, data_dir, home_dir};
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+const VERSION_FILES: &[&str] = &[".nvmrc", ".node-version"];
+
+pub(super) type Version = (u64, u64, u64);
+
+// Parses "v18.17.0", "18.17" or "18.x" into components, leaving wildcard or
+// missing parts as None.
+fn version_parts(text: &str) -> Option<Vec<Option<u64>>> {
+    let text = text.trim().trim_start_matches(['v', 'V', '=']);
+    let core = text.split(['-', '+']).next().unwrap_or_default();
+    if core.is_empty() {
+        return None;
+    }
+    let mut parts = Vec::new();
+    for part in core.split('.').take(3) {
+        match part {
+            "x" | "X" | "*" => parts.push(None),
+            _ => parts.push(Some(part.parse().ok()?)),
+        }
+    }
+    Some(parts)
+}
+
+pub(super) fn parse_version(text: &str) -> Option<Version> {
+    let parts = version_parts(text)?;
+    let part = |index: usize| parts.get(index).copied().flatten();
+    Some((part(0)?, part(1).unwrap_or(0), part(2).unwrap_or(0)))
+}
+
+fn lower_bound(parts: &[Option<u64>]) -> Version {
+    let part = |index: usize| parts.get(index).copied().flatten().unwrap_or(0);
+    (part(0), part(1), part(2))
+}
+
+fn prefix_matches(version: Version, parts: &[Option<u64>]) -> bool {
+    let actual = [version.0, version.1, version.2];
+    parts
+        .iter()
+        .zip(actual)
+        .all(|(expected, actual)| expected.map(|value| value == actual).unwrap_or(true))
+}
+
+fn comparator_matches(version: Version, comparator: &str) -> bool {
+    let (operator, rest) = ["^", "~", ">=", "<=", ">", "<"]
+        .iter()
+        .find_map(|operator| {
+            comparator
+                .strip_prefix(operator)
+                .map(|rest| (*operator, rest))
+        })
+        .unwrap_or(("", comparator));
+    let parts = match version_parts(rest) {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let bound = lower_bound(&parts);
+    match operator {
+        "^" => version.0 == bound.0 && version >= bound,
+        // "~18" allows any 18.x, "~18.17" only 18.17.x.
+        "~" => version >= bound && prefix_matches(version, &parts[..parts.len().min(2)]),
+        ">=" => version >= bound,
+        "<=" => version <= bound || prefix_matches(version, &parts),
+        ">" => version > bound && !prefix_matches(version, &parts),
+        "<" => version < bound,
+        _ => prefix_matches(version, &parts),
+    }
+}
+
+/// Matches an installed version against a pin such as "18", "^18.17.0",
+/// ">=16 <20" or "18.x || 20.x".
+pub(super) fn range_matches(version: Version, range: &str) -> bool {
+    range.split("||").any(|alternative| {
+        // Joins operators written apart from their version, as in ">= 16".
+        let mut comparators: Vec<String> = Vec::new();
+        let mut pending = String::new();
+        for token in alternative.split_whitespace() {
+            pending.push_str(token);
+            if !token
+                .chars()
+                .all(|ch| matches!(ch, '<' | '>' | '=' | '^' | '~'))
+            {
+                comparators.push(mem::take(&mut pending));
+            }
+        }
+        !comparators.is_empty()
+            && comparators
+                .iter()
+                .all(|comparator| comparator_matches(version, comparator))
+    })
+}
+
+fn package_pins(project_dir: &Path, pins: &mut Vec<String>) {
+    let pkg = match fs::read_to_string(project_dir.join("package.json"))
+        .ok()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+    {
+        Some(pkg) => pkg,
+        None => return,
+    };
+    for section in ["engines", "volta"] {
+        if let Some(value) = pkg
+            .get(section)
+            .and_then(|value| value.get("node"))
+            .and_then(|value| value.as_str())
+        {
+            pins.push(value.to_string());
+        }
+    }
+}
+
+fn project_pins(project_dirs: &[PathBuf]) -> Vec<String> {
+    let mut pins = Vec::new();
+    for dir in project_dirs {
+        for name in VERSION_FILES {
+            if let Ok(data) = fs::read_to_string(dir.join(name)) {
+                if let Some(line) = data.lines().map(str::trim).find(|line| !line.is_empty()) {
+                    pins.push(line.to_string());
+                }
+            }
+        }
+        package_pins(dir, &mut pins);
+    }
+    pins
+}
+
+// Aliases such as "lts/*" or "node" cannot be resolved offline, so they are
+// taken to mean the newest installed version.
+fn used_versions(installed: &[(PathBuf, Version)], pins: &[String]) -> Vec<Version> {
+    let newest = installed.iter().map(|(_, version)| *version).max();
+    let mut used = Vec::new();
+    for (_, version) in installed {
+        let is_used = pins.iter().any(|pin| {
+            if pin.contains(|ch: char| ch.is_ascii_digit()) {
+                range_matches(*version, pin)
+            } else {
+                Some(*version) == newest
+            }
+        });
+        if is_used {
+            used.push(*version);
+        }
+    }
+    used
+}
+
+fn installed_versions(dir: &Path) -> Vec<(PathBuf, Version)> {
+    child_dirs(dir)
+        .into_iter()
+        .filter_map(|path| {
+            let version = parse_version(&file_name(&path))?;
+            Some((path, version))
+        })
+        .collect()
+}
+
+fn version_candidates(
+    dir: &Path,
+    label: &str,
+    default_pin: Option<String>,
+    project_pins: &[String],
+) -> Vec<CacheCandidate> {
+    let installed = installed_versions(dir);
+    let mut pins = project_pins.to_vec();
+    pins.extend(default_pin);
+    let used = used_versions(&installed, &pins);
+    installed
+        .into_iter()
+        .map(|(path, version)| CacheCandidate {
+            label: format!("{label} - {}", file_name(&path)),
+            path,
+            expand_children: false,
+            kind: Some(CacheKind::Toolchain),
+            in_use: Some(used.contains(&version)),
+        })
+        .collect()
+}
+
+fn nvm_default(nvm_dir: &Path) -> Option<String> {
+    let data = fs::read_to_string(nvm_dir.join("alias").join("default")).ok()?;
+    Some(data.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+fn fnm_default(fnm_dir: &Path) -> Option<String> {
+    let target = fs::read_link(fnm_dir.join("aliases").join("default")).ok()?;
+    target
+        .ancestors()
+        .map(file_name)
+        .find(|name| parse_version(name).is_some())
+}
+
+fn volta_default(volta_home: &Path) -> Option<String> {
+    let path = volta_home.join("tools").join("user").join("platform.json");
+    let platform: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    platform
+        .get("node")?
+        .get("runtime")?
+        .as_str()
+        .map(|value| value.to_string())
+}
+
+fn download_cache(path: PathBuf, label: &str) -> CacheCandidate {
+    CacheCandidate {
+        path,
+        label: label.to_string(),
+        expand_children: false,
+        kind: Some(CacheKind::Download),
+        in_use: None,
+    }
+}
+
+pub(super) fn candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+    let pins = project_pins(project_dirs);
+
+    if let Some(nvm_dir) = env_or_home("NVM_DIR", &[".nvm"]) {
+        candidates.extend(version_candidates(
+            &nvm_dir.join("versions").join("node"),
+            "nvm Node",
+            nvm_default(&nvm_dir),
+            &pins,
+        ));
+    }
+
+    let fnm_dirs: Vec<PathBuf> = match env_path("FNM_DIR") {
+        Some(path) => vec![path],
+        None => [
+            data_dir().map(|path| path.join("fnm")),
+            home_dir().map(|path| path.join(".fnm")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+    };
+    for fnm_dir in fnm_dirs {
+        candidates.extend(version_candidates(
+            &fnm_dir.join("node-versions"),
+            "fnm Node",
+            fnm_default(&fnm_dir),
+            &pins,
+        ));
+    }
+
+    if let Some(volta_home) = env_or_home("VOLTA_HOME", &[".volta"]) {
+        candidates.extend(version_candidates(
+            &volta_home.join("tools").join("image").join("node"),
+            "Volta Node",
+            volta_default(&volta_home),
+            &pins,
+        ));
+    }
+
+    if let Some(path) = env_or_home("COREPACK_HOME", &[".cache", "node", "corepack"]) {
+        candidates.push(download_cache(path, "corepack cache"));
+    }
+    if let Some(path) = env_path("BUN_INSTALL_CACHE_DIR")
+        .or_else(|| env_path("BUN_INSTALL").map(|path| path.join("install").join("cache")))
+        .or_else(|| home_dir().map(|path| path.join(".bun").join("install").join("cache")))
+    {
+        candidates.push(download_cache(path, "bun install cache"));
+    }
+    if let Some(path) = env_path("DENO_DIR").or_else(|| cache_dir().map(|path| path.join("deno"))) {
+        candidates.push(download_cache(path, "deno cache"));
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::range_matches;
+
+    #[test]
+    fn tilde_without_a_minor_allows_any_minor() {
+        assert!(range_matches((18, 0, 0), "~18"));
+        assert!(range_matches((18, 19, 1), "~18"));
+        assert!(!range_matches((19, 0, 0), "~18"));
+        assert!(!range_matches((17, 9, 0), "~18"));
+    }
+
+    #[test]
+    fn tilde_with_a_minor_locks_it() {
+        assert!(range_matches((18, 17, 0), "~18.17"));
+        assert!(range_matches((18, 17, 5), "~18.17"));
+        assert!(!range_matches((18, 18, 0), "~18.17"));
+        assert!(!range_matches((18, 16, 9), "~18.17"));
+        assert!(range_matches((18, 18, 0), "~18.x"));
+    }
+
+    #[test]
+    fn caret_on_a_zero_major_stays_below_one() {
+        assert!(range_matches((0, 0, 0), "^0.x"));
+        assert!(range_matches((0, 9, 3), "^0.x"));
+        assert!(!range_matches((1, 0, 0), "^0.x"));
+    }
+}
@@ -0,0 +1,102 @@
+use super::{env_path, CacheCandidate};
+use crate::types::CacheKind;
+use dirs::{cache_dir, data_dir, home_dir};
+use std::env;
+use std::path::PathBuf;
+
+fn env_or_cache(variable: &str, name: &str) -> Option<PathBuf> {
+    env_path(variable).or_else(|| cache_dir().map(|path| path.join(name)))
+}
+
+fn candidate(path: PathBuf, label: &str, kind: CacheKind) -> CacheCandidate {
+    CacheCandidate {
+        path,
+        label: label.to_string(),
+        expand_children: false,
+        kind: Some(kind),
+        in_use: None,
+    }
+}
+
+fn conda_package_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(value) = env::var_os("CONDA_PKGS_DIRS") {
+        dirs.extend(
+            value
+                .to_string_lossy()
+                .split([',', ';'])
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from),
+        );
+    }
+    if let Some(prefix) = env::var_os("CONDA_PREFIX").filter(|value| !value.is_empty()) {
+        dirs.push(PathBuf::from(prefix).join("pkgs"));
+    }
+    if let Some(home) = home_dir() {
+        for install in [
+            ".conda",
+            "miniconda3",
+            "anaconda3",
+            "miniforge3",
+            "mambaforge",
+        ] {
+            dirs.push(home.join(install).join("pkgs"));
+        }
+    }
+    dirs
+}
+
+pub(super) fn candidates() -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+
+    if let Some(path) = env_or_cache("PIP_CACHE_DIR", "pip") {
+        candidates.push(candidate(path, "pip cache", CacheKind::Download));
+    }
+    if let Some(path) = env_or_cache("POETRY_CACHE_DIR", "pypoetry") {
+        candidates.push(candidate(
+            path.join("cache"),
+            "Poetry cache",
+            CacheKind::Download,
+        ));
+        candidates.push(candidate(
+            path.join("artifacts"),
+            "Poetry artifacts",
+            CacheKind::Download,
+        ));
+    }
+    if let Some(path) = env_or_cache("UV_CACHE_DIR", "uv") {
+        candidates.push(candidate(path, "uv cache", CacheKind::Download));
+    }
+
+    // pipx moved its default home from ~/.local/pipx to the platform data dir.
+    let pipx_homes = match env_path("PIPX_HOME") {
+        Some(path) => vec![path],
+        None => [
+            home_dir().map(|path| path.join(".local").join("pipx")),
+            data_dir().map(|path| path.join("pipx")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+    };
+    for home in pipx_homes {
+        candidates.push(candidate(
+            home.join("venvs"),
+            "pipx environments",
+            CacheKind::Toolchain,
+        ));
+    }
+
+    for path in conda_package_dirs() {
+        let install = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let label = format!("conda packages - {install}");
+        candidates.push(candidate(path, &label, CacheKind::Download));
+    }
+
+    candidates
+}
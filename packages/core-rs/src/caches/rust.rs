@@ -0,0 +1,138 @@
+use super::{child_dirs, env_or_home, CacheCandidate};
+use crate::types::CacheKind;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TOOLCHAIN_FILES: &[&str] = &["rust-toolchain.toml", "rust-toolchain"];
+
+fn toolchain_channel(data: &str) -> Option<String> {
+    let trimmed = data.trim();
+    if !trimmed.contains('=') {
+        return trimmed
+            .lines()
+            .next()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty());
+    }
+    let value: toml::Value = toml::from_str(trimmed).ok()?;
+    value
+        .get("toolchain")?
+        .get("channel")?
+        .as_str()
+        .map(|channel| channel.trim().to_string())
+}
+
+fn project_pins(project_dirs: &[PathBuf]) -> Vec<String> {
+    let mut pins = Vec::new();
+    for dir in project_dirs {
+        let channel = TOOLCHAIN_FILES
+            .iter()
+            .filter_map(|name| fs::read_to_string(dir.join(name)).ok())
+            .find_map(|data| toolchain_channel(&data));
+        if let Some(channel) = channel {
+            if !pins.contains(&channel) {
+                pins.push(channel);
+            }
+        }
+    }
+    pins
+}
+
+fn rustup_pins(rustup_home: &Path) -> Vec<String> {
+    let data = match fs::read_to_string(rustup_home.join("settings.toml")) {
+        Ok(data) => data,
+        Err(_) => return Vec::new(),
+    };
+    let settings: toml::Value = match toml::from_str(&data) {
+        Ok(settings) => settings,
+        Err(_) => return Vec::new(),
+    };
+    let mut pins: Vec<String> = settings
+        .get("default_toolchain")
+        .and_then(|value| value.as_str())
+        .map(|value| vec![value.to_string()])
+        .unwrap_or_default();
+    if let Some(overrides) = settings.get("overrides").and_then(|value| value.as_table()) {
+        pins.extend(
+            overrides
+                .values()
+                .filter_map(|value| value.as_str())
+                .map(|value| value.to_string()),
+        );
+    }
+    pins
+}
+
+// Toolchain directories carry the host triple, so "stable" matches
+// "stable-x86_64-unknown-linux-gnu" but "nightly" must not match a dated
+// "nightly-2024-01-01-..." install.
+fn matches_channel(toolchain: &str, channel: &str) -> bool {
+    if toolchain == channel {
+        return true;
+    }
+    toolchain
+        .strip_prefix(channel)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .map(|rest| !rest.starts_with(|ch: char| ch.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
+fn candidate(path: PathBuf, label: &str, kind: CacheKind) -> CacheCandidate {
+    CacheCandidate {
+        path,
+        label: label.to_string(),
+        expand_children: false,
+        kind: Some(kind),
+        in_use: None,
+    }
+}
+
+pub(super) fn candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
+    let mut candidates = Vec::new();
+
+    if let Some(cargo_home) = env_or_home("CARGO_HOME", &[".cargo"]) {
+        let registry = cargo_home.join("registry");
+        candidates.push(candidate(
+            registry.join("src"),
+            "Cargo registry sources",
+            CacheKind::Derived,
+        ));
+        candidates.push(candidate(
+            registry.join("cache"),
+            "Cargo registry downloads",
+            CacheKind::Download,
+        ));
+        candidates.push(candidate(
+            registry.join("index"),
+            "Cargo registry index",
+            CacheKind::Download,
+        ));
+        candidates.push(candidate(
+            cargo_home.join("git").join("checkouts"),
+            "Cargo git checkouts",
+            CacheKind::Derived,
+        ));
+    }
+
+    if let Some(rustup_home) = env_or_home("RUSTUP_HOME", &[".rustup"]) {
+        let mut pins = project_pins(project_dirs);
+        pins.extend(rustup_pins(&rustup_home));
+        for toolchain in child_dirs(&rustup_home.join("toolchains")) {
+            let name = toolchain
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            let in_use = pins.iter().any(|pin| matches_channel(&name, pin));
+            candidates.push(CacheCandidate {
+                label: format!("Rust toolchain - {name}"),
+                path: toolchain,
+                expand_children: false,
+                kind: Some(CacheKind::Toolchain),
+                in_use: Some(in_use),
+            });
+        }
+    }
+
+    candidates
+}
@@ -1,6 +1,11 @@
+use crate::detect::detect_artifacts;
+use crate::prune::{plan_prune, PruneOptions};
+use crate::walk::{tree_size, with_threads};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
@@ -14,7 +19,9 @@ pub struct DeleteEntry {
 #[serde(rename_all = "camelCase")]
 pub struct DeletePlanItem {
     pub path: String,
+    pub project_path: String,
     pub size_bytes: u64,
+    pub prune_bytes: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,43 +29,36 @@ pub struct DeletePlanItem {
 pub struct DeletePlan {
     pub items: Vec<DeletePlanItem>,
     pub total_bytes: u64,
+    pub prune_total_bytes: u64,
 }
 
 fn path_id(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
 
-fn directory_size_bytes(dir: &Path) -> u64 {
-    if let Ok(meta) = fs::metadata(dir) {
-        if meta.is_file() {
-            return meta.len();
-        }
-    }
-    WalkDir::new(dir)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().is_file())
-        .filter_map(|entry| entry.metadata().ok())
-        .map(|meta| meta.len())
-        .sum()
+struct Target {
+    path: PathBuf,
+    project: PathBuf,
+    // Artifacts found inside a project are skipped when they hold protected
+    // files; folders the user picked directly are not.
+    is_artifact: bool,
 }
 
-fn collect_targets(entries: &[DeleteEntry], deps_only: bool) -> Vec<PathBuf> {
+fn collect_targets(entries: &[DeleteEntry], deps_only: bool) -> Vec<Target> {
     let mut targets = Vec::new();
     let mut seen = HashSet::new();
 
     for entry in entries {
         let entry_path = entry.path.clone();
         if deps_only && !entry.is_cache {
-            let candidates = [entry_path.join("node_modules"), entry_path.join(".cache")];
-            for candidate in candidates {
-                if !candidate.exists() {
-                    continue;
-                }
+            for candidate in detect_artifacts(&entry_path) {
                 let key = path_id(&candidate);
                 if seen.insert(key) {
-                    targets.push(candidate);
+                    targets.push(Target {
+                        path: candidate,
+                        project: entry_path.clone(),
+                        is_artifact: true,
+                    });
                 }
             }
             continue;
@@ -69,25 +69,128 @@ fn collect_targets(entries: &[DeleteEntry], deps_only: bool) -> Vec<PathBuf> {
         }
         let key = path_id(&entry_path);
         if seen.insert(key) {
-            targets.push(entry_path);
+            targets.push(Target {
+                path: entry_path.clone(),
+                project: entry_path,
+                is_artifact: false,
+            });
         }
     }
-
     targets
 }
 
-pub fn build_delete_plan(entries: &[DeleteEntry], deps_only: bool) -> DeletePlan {
-    let mut items = Vec::new();
+// A cache can be selected together with one nested inside it; removing the
+// outer folder covers both, so the inner one is dropped.
+fn drop_nested(items: &mut Vec<DeletePlanItem>) {
+    let paths: Vec<PathBuf> = items.iter().map(|item| PathBuf::from(&item.path)).collect();
+    items.retain(|item| {
+        let path = Path::new(&item.path);
+        !paths
+            .iter()
+            .any(|other| other != path && path.starts_with(other))
+    });
+}
+
+/// Builds the delete plan, sizing targets on `threads` workers (zero picks
+/// one per core). With `prune` set, caches that support partial pruning also
+/// report what the prune would reclaim next to their full size.
+pub fn build_delete_plan(
+    entries: &[DeleteEntry],
+    deps_only: bool,
+    prune: Option<&PruneOptions>,
+    threads: usize,
+) -> DeletePlan {
+    let targets = collect_targets(entries, deps_only);
+    let caches: HashSet<&Path> = entries
+        .iter()
+        .filter(|entry| entry.is_cache)
+        .map(|entry| entry.path.as_path())
+        .collect();
+    let mut items: Vec<DeletePlanItem> = with_threads(threads, || {
+        targets
+            .par_iter()
+            .filter_map(|target| {
+                let size = tree_size(&target.path);
+                if target.is_artifact && !size.protected_files.is_empty() {
+                    return None;
+                }
+                Some(DeletePlanItem {
+                    path: target.path.to_string_lossy().to_string(),
+                    project_path: target.project.to_string_lossy().to_string(),
+                    size_bytes: size.bytes,
+                    prune_bytes: prune
+                        .filter(|_| caches.contains(target.path.as_path()))
+                        .and_then(|options| plan_prune(&target.path, options))
+                        .map(|summary| summary.reclaimed_bytes),
+                })
+            })
+            .collect()
+    });
+    drop_nested(&mut items);
+
     let mut total_bytes = 0u64;
+    let mut prune_total_bytes = 0u64;
+    for item in &items {
+        total_bytes = total_bytes.saturating_add(item.size_bytes);
+        prune_total_bytes =
+            prune_total_bytes.saturating_add(item.prune_bytes.unwrap_or(item.size_bytes));
+    }
+
+    DeletePlan {
+        items,
+        total_bytes,
+        prune_total_bytes,
+    }
+}
 
-    for target in collect_targets(entries, deps_only) {
-        let size_bytes = directory_size_bytes(&target);
-        total_bytes = total_bytes.saturating_add(size_bytes);
-        items.push(DeletePlanItem {
-            path: target.to_string_lossy().to_string(),
-            size_bytes,
-        });
+#[cfg(unix)]
+fn make_writable(path: &Path, meta: &fs::Metadata) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = meta.permissions();
+    permissions.set_mode(permissions.mode() | 0o700);
+    fs::set_permissions(path, permissions)
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::permissions_set_readonly_false)]
+fn make_writable(path: &Path, meta: &fs::Metadata) -> io::Result<()> {
+    let mut permissions = meta.permissions();
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions)
+}
+
+// Go writes its module cache read-only, and a read-only directory keeps its
+// entries from being unlinked, so permissions are lifted before retrying.
+fn clear_readonly(path: &Path) {
+    for entry in WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+    {
+        if entry.path_is_symlink() {
+            continue;
+        }
+        if let Ok(meta) = entry.metadata() {
+            if meta.permissions().readonly() {
+                let _ = make_writable(entry.path(), &meta);
+            }
+        }
     }
+}
 
-    DeletePlan { items, total_bytes }
+pub fn remove_path(path: &Path) -> io::Result<()> {
+    let remove = |path: &Path| {
+        if fs::symlink_metadata(path)?.is_dir() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        }
+    };
+    match remove(path) {
+        Err(error) if error.kind() == io::ErrorKind::PermissionDenied => {
+            clear_readonly(path);
+            remove(path)
+        }
+        result => result,
+    }
 }
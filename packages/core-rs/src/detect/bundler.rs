@@ -0,0 +1,67 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &["vendor/bundle", ".bundle"];
+
+pub struct BundlerDetector;
+
+fn gem_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("gem")?;
+    if !rest.starts_with([' ', '\t', '(']) {
+        return None;
+    }
+    let rest = rest.trim_start_matches(['(', ' ', '\t']);
+    let quote = rest.chars().next().filter(|ch| *ch == '"' || *ch == '\'')?;
+    let value = &rest[1..];
+    let end = value.find(quote)?;
+    Some(value[..end].to_string())
+}
+
+impl ProjectDetector for BundlerDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Ruby
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["Gemfile", "Gemfile.lock"]
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let mut gems: Vec<String> = Vec::new();
+        let gemfile = manifests
+            .iter()
+            .find(|path| path.ends_with("Gemfile"))
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default();
+
+        for line in gemfile.lines() {
+            if let Some(name) = gem_name(line.trim()) {
+                if !gems.contains(&name) {
+                    gems.push(name);
+                }
+            }
+        }
+
+        Some(ManifestDetails {
+            dependency_count: gems.len(),
+            ..ManifestDetails::default()
+        })
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &["vendor", ".bundle"]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        if !project_dir.join("Gemfile.lock").is_file() {
+            return Vec::new();
+        }
+        existing_dirs(project_dir, ARTIFACT_DIRS)
+    }
+}
@@ -0,0 +1,124 @@
+use super::{existing_dirs, expand_member_pattern, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &["target"];
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+pub struct CargoDetector;
+
+fn read_cargo_toml(path: &Path) -> Option<toml::Table> {
+    let data = fs::read_to_string(path).ok()?;
+    data.parse::<toml::Table>().ok()
+}
+
+fn collect_dependency_names(table: &toml::Table, names: &mut BTreeSet<String>) {
+    for key in DEPENDENCY_TABLES {
+        if let Some(deps) = table.get(*key).and_then(|value| value.as_table()) {
+            names.extend(deps.keys().cloned());
+        }
+    }
+
+    if let Some(targets) = table.get("target").and_then(|value| value.as_table()) {
+        for target in targets.values().filter_map(|value| value.as_table()) {
+            collect_dependency_names(target, names);
+        }
+    }
+}
+
+fn string_list(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|value| value.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(|value| value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl ProjectDetector for CargoDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Rust
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["Cargo.toml", "Cargo.lock"]
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut tables = Vec::new();
+        for path in manifests.iter().filter(|path| path.ends_with("Cargo.toml")) {
+            if let Some(table) = read_cargo_toml(path) {
+                tables.push((path.parent() == Some(project_dir), table));
+            }
+        }
+
+        let root = tables
+            .iter()
+            .find(|(is_root, _)| *is_root)
+            .map(|(_, table)| table);
+        if root.is_none() && !manifests.iter().any(|path| path.ends_with("Cargo.lock")) {
+            return None;
+        }
+
+        let package = root
+            .and_then(|table| table.get("package"))
+            .and_then(|value| value.as_table());
+        let name = package
+            .and_then(|package| package.get("name"))
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| value.to_string());
+        let keywords = string_list(package.and_then(|package| package.get("keywords")));
+
+        let mut names = BTreeSet::new();
+        for (_, table) in &tables {
+            collect_dependency_names(table, &mut names);
+            if let Some(workspace) = table.get("workspace").and_then(|value| value.as_table()) {
+                collect_dependency_names(workspace, &mut names);
+            }
+        }
+
+        Some(ManifestDetails {
+            name,
+            dependency_count: names.len(),
+            keywords,
+            scripts: Vec::new(),
+        })
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let table = match read_cargo_toml(&project_dir.join("Cargo.toml")) {
+            Some(table) => table,
+            None => return Vec::new(),
+        };
+        let workspace = match table.get("workspace").and_then(|value| value.as_table()) {
+            Some(workspace) => workspace,
+            None => return Vec::new(),
+        };
+
+        let excluded: Vec<PathBuf> = string_list(workspace.get("exclude"))
+            .iter()
+            .flat_map(|pattern| expand_member_pattern(project_dir, pattern))
+            .collect();
+
+        string_list(workspace.get("members"))
+            .iter()
+            .flat_map(|pattern| expand_member_pattern(project_dir, pattern))
+            .filter(|member| !excluded.contains(member))
+            .filter(|member| member.join("Cargo.toml").is_file())
+            .collect()
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        existing_dirs(project_dir, ARTIFACT_DIRS)
+    }
+}
@@ -0,0 +1,87 @@
+use super::{ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct ComposerDetector;
+
+fn is_platform_package(name: &str) -> bool {
+    name == "php" || name.starts_with("ext-") || name.starts_with("lib-")
+}
+
+impl ProjectDetector for ComposerDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Php
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["composer.json", "composer.lock"]
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let composer: serde_json::Value = manifests
+            .iter()
+            .find(|path| path.ends_with("composer.json"))
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|data| serde_json::from_str(&data).ok())?;
+
+        let name = composer
+            .get("name")
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| value.to_string());
+
+        let keywords = composer
+            .get("keywords")
+            .and_then(|value| value.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(|value| value.to_string()))
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        let scripts = composer
+            .get("scripts")
+            .and_then(|value| value.as_object())
+            .map(|map| {
+                map.values()
+                    .filter_map(|value| value.as_str().map(|value| value.to_string()))
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        let dependency_count = ["require", "require-dev"]
+            .iter()
+            .filter_map(|key| composer.get(key))
+            .filter_map(|value| value.as_object())
+            .flat_map(|map| map.keys())
+            .filter(|name| !is_platform_package(name))
+            .count();
+
+        Some(ManifestDetails {
+            name,
+            dependency_count,
+            keywords,
+            scripts,
+        })
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &["vendor"]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let vendor = project_dir.join("vendor");
+        if vendor.is_dir() && project_dir.join("composer.lock").is_file() {
+            vec![vendor]
+        } else {
+            Vec::new()
+        }
+    }
+}
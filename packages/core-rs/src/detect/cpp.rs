@@ -0,0 +1,242 @@
+use super::{ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const BUILD_TREE_DEPTH: usize = 3;
+const BUILD_TREE_SKIP: &[&str] = &[".git", "node_modules", "third_party", "vendor"];
+const BAZEL_WORKSPACE_FILES: &[&str] = &["WORKSPACE", "WORKSPACE.bazel", "MODULE.bazel"];
+
+pub struct CppDetector;
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+fn is_build_tree(dir: &Path) -> bool {
+    dir.join("CMakeCache.txt").is_file() || dir.join("meson-private").is_dir()
+}
+
+fn call_arguments<'a>(data: &'a str, function: &str) -> Vec<&'a str> {
+    let pattern = format!("{function}(");
+    let mut arguments = Vec::new();
+    let mut rest = data;
+    while let Some(start) = rest.find(&pattern) {
+        let preceding = rest[..start].chars().last();
+        let after = &rest[start + pattern.len()..];
+        if preceding.is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '_') {
+            rest = after;
+            continue;
+        }
+        let end = after.find(')').unwrap_or(after.len());
+        arguments.push(&after[..end]);
+        rest = &after[end..];
+    }
+    arguments
+}
+
+fn first_word(arguments: &str) -> Option<String> {
+    let word: String = arguments
+        .trim_start()
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|ch| !ch.is_whitespace() && !matches!(ch, '"' | '\'' | ',' | ')'))
+        .collect();
+    if word.is_empty() {
+        None
+    } else {
+        Some(word)
+    }
+}
+
+fn keyword_string(arguments: &str, key: &str) -> Option<String> {
+    let start = arguments.find(key)? + key.len();
+    let rest = arguments[start..]
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    let quote = rest.chars().next().filter(|ch| *ch == '"' || *ch == '\'')?;
+    let value = &rest[1..];
+    let end = value.find(quote)?;
+    Some(value[..end].to_string())
+}
+
+fn strip_line_comments(data: &str) -> String {
+    data.lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+fn manifest_details(path: &Path) -> (Option<String>, usize) {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => strip_line_comments(&data),
+        Err(_) => return (None, 0),
+    };
+    match file_name(path).as_str() {
+        "CMakeLists.txt" => {
+            let name = call_arguments(&data, "project")
+                .first()
+                .and_then(|arguments| first_word(arguments));
+            let count = call_arguments(&data, "find_package").len()
+                + call_arguments(&data, "FetchContent_Declare").len();
+            (name, count)
+        }
+        "meson.build" => {
+            let name = call_arguments(&data, "project")
+                .first()
+                .and_then(|arguments| first_word(arguments));
+            (name, call_arguments(&data, "dependency").len())
+        }
+        _ => {
+            let name = call_arguments(&data, "module")
+                .first()
+                .and_then(|arguments| keyword_string(arguments, "name"))
+                .or_else(|| {
+                    call_arguments(&data, "workspace")
+                        .first()
+                        .and_then(|arguments| keyword_string(arguments, "name"))
+                });
+            let count = call_arguments(&data, "bazel_dep").len()
+                + call_arguments(&data, "http_archive").len()
+                + call_arguments(&data, "git_repository").len();
+            (name, count)
+        }
+    }
+}
+
+fn subdirectories(project_dir: &Path, manifest: &str, function: &str) -> Vec<PathBuf> {
+    let data = match fs::read_to_string(project_dir.join(manifest)) {
+        Ok(data) => strip_line_comments(&data),
+        Err(_) => return Vec::new(),
+    };
+    call_arguments(&data, function)
+        .iter()
+        .filter_map(|arguments| first_word(arguments))
+        .filter(|name| !name.contains("${"))
+        .map(|name| project_dir.join(name))
+        .filter(|member| {
+            member
+                .components()
+                .all(|part| !matches!(part, Component::ParentDir | Component::CurDir))
+        })
+        .collect()
+}
+
+// Bazel keeps its output base outside the source tree, under an output user
+// root named `_bazel_<user>`, and links it back through `bazel-*` symlinks
+// pointing into `execroot/<workspace>`. Anything else is left alone.
+fn bazel_output_base(link: &Path) -> Option<PathBuf> {
+    let target = fs::read_link(link).ok()?;
+    let target = if target.is_absolute() {
+        target
+    } else {
+        link.parent()?.join(target)
+    };
+    let workspace = target.ancestors().find(|ancestor| {
+        ancestor
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|name| name == "execroot")
+    })?;
+    let base = workspace.parent()?.parent()?;
+    let user_root = base.parent()?.file_name()?.to_string_lossy();
+    if !user_root.starts_with("_bazel_") || !workspace.is_dir() {
+        return None;
+    }
+    Some(base.to_path_buf())
+}
+
+fn bazel_outputs(project_dir: &Path) -> Vec<PathBuf> {
+    if !BAZEL_WORKSPACE_FILES
+        .iter()
+        .any(|name| project_dir.join(name).is_file())
+    {
+        return Vec::new();
+    }
+    let entries = match fs::read_dir(project_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut links = Vec::new();
+    let mut bases = Vec::new();
+    for entry in entries.flatten() {
+        if !entry.file_name().to_string_lossy().starts_with("bazel-")
+            || !entry
+                .file_type()
+                .map(|kind| kind.is_symlink())
+                .unwrap_or(false)
+        {
+            continue;
+        }
+        let base = match bazel_output_base(&entry.path()) {
+            Some(base) if !project_dir.starts_with(&base) => base,
+            _ => continue,
+        };
+        links.push(entry.path());
+        bases.push(base);
+    }
+    links.sort();
+    bases.sort();
+    bases.dedup();
+    links.extend(bases);
+    links
+}
+
+impl ProjectDetector for CppDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Cpp
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &[
+            "CMakeLists.txt",
+            "meson.build",
+            "WORKSPACE",
+            "WORKSPACE.bazel",
+            "MODULE.bazel",
+        ]
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut details = ManifestDetails::default();
+        for path in manifests {
+            let (name, count) = manifest_details(path);
+            details.dependency_count += count;
+            if path.parent() == Some(project_dir) && details.name.is_none() {
+                details.name = name;
+            }
+        }
+        Some(details)
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let mut members = subdirectories(project_dir, "CMakeLists.txt", "add_subdirectory");
+        members.extend(subdirectories(project_dir, "meson.build", "subdir"));
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        bazel_outputs(project_dir)
+    }
+
+    // The scanner asks on behalf of every project, so a build tree only
+    // counts under one that is built with CMake, Meson or Bazel.
+    fn is_nested_artifact(&self, project_dir: &Path, dir: &Path) -> bool {
+        let relative = match dir.strip_prefix(project_dir) {
+            Ok(relative) => relative,
+            Err(_) => return false,
+        };
+        relative.components().count() <= BUILD_TREE_DEPTH
+            && !relative
+                .components()
+                .any(|part| BUILD_TREE_SKIP.contains(&part.as_os_str().to_string_lossy().as_ref()))
+            && is_build_tree(dir)
+            && !self.find_manifests(project_dir).is_empty()
+    }
+}
@@ -0,0 +1,98 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &[".dart_tool", "build"];
+const EPHEMERAL_DIRS: &[&str] = &[
+    "linux/flutter/ephemeral",
+    "windows/flutter/ephemeral",
+    "macos/Flutter/ephemeral",
+    "ios/Flutter/ephemeral",
+];
+const DEPENDENCY_SECTIONS: &[&str] = &["dependencies", "dev_dependencies"];
+
+pub struct DartDetector;
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn read_pubspec(data: &str) -> ManifestDetails {
+    let mut details = ManifestDetails::default();
+    let mut section: Option<&str> = None;
+    let mut child_indent: Option<usize> = None;
+
+    for line in data.lines() {
+        let content = line.split('#').next().unwrap_or_default().trim_end();
+        if content.trim().is_empty() {
+            continue;
+        }
+
+        let indent = indentation(content);
+        if indent == 0 {
+            let key = content.split(':').next().unwrap_or_default().trim();
+            section = DEPENDENCY_SECTIONS
+                .iter()
+                .find(|name| **name == key)
+                .copied();
+            child_indent = None;
+            if key == "name" {
+                let value = content
+                    .split_once(':')
+                    .map(|(_, value)| value)
+                    .unwrap_or("");
+                let value = value.trim().trim_matches(|ch| ch == '"' || ch == '\'');
+                if !value.is_empty() {
+                    details.name = Some(value.to_string());
+                }
+            }
+            continue;
+        }
+
+        if section.is_none() || !content.contains(':') {
+            continue;
+        }
+        let expected = *child_indent.get_or_insert(indent);
+        if indent == expected {
+            details.dependency_count += 1;
+        }
+    }
+
+    details
+}
+
+impl ProjectDetector for DartDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Dart
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["pubspec.yaml", "pubspec.lock"]
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let pubspec = manifests
+            .iter()
+            .find(|path| path.ends_with("pubspec.yaml"))
+            .and_then(|path| fs::read_to_string(path).ok());
+        match pubspec {
+            Some(data) => Some(read_pubspec(&data)),
+            None => Some(ManifestDetails::default()),
+        }
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = existing_dirs(project_dir, ARTIFACT_DIRS);
+        dirs.extend(existing_dirs(project_dir, EPHEMERAL_DIRS));
+        dirs
+    }
+}
@@ -0,0 +1,148 @@
+use super::{existing_dirs, strip_xml_comments, xml_elements, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const PROJECT_EXTENSIONS: &[&str] = &["csproj", "fsproj", "vbproj"];
+const ARTIFACT_DIRS: &[&str] = &["bin", "obj"];
+
+pub struct DotnetDetector;
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_lowercase()
+}
+
+fn attribute(element: &str, name: &str) -> Option<String> {
+    let head = element.split('>').next().unwrap_or(element);
+    let pattern = format!("{name}=");
+    let start = head.find(&pattern)? + pattern.len();
+    let rest = &head[start..];
+    let quote = rest.chars().next().filter(|ch| *ch == '"' || *ch == '\'')?;
+    let value = &rest[1..];
+    let end = value.find(quote)?;
+    Some(value[..end].trim().to_string())
+}
+
+fn package_references(path: &Path, names: &mut BTreeSet<String>) {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => strip_xml_comments(&data),
+        Err(_) => return,
+    };
+    for element in xml_elements(&data, "PackageReference") {
+        if let Some(include) = attribute(element, "Include") {
+            names.insert(include.to_lowercase());
+        }
+    }
+}
+
+fn solution_projects(solution: &Path) -> Vec<PathBuf> {
+    let data = match fs::read_to_string(solution) {
+        Ok(data) => data,
+        Err(_) => return Vec::new(),
+    };
+    let base = solution.parent().unwrap_or(Path::new(""));
+    data.lines()
+        .filter(|line| line.trim_start().starts_with("Project("))
+        .filter_map(|line| line.split(',').nth(1))
+        .map(|value| value.trim().trim_matches('"').replace('\\', "/"))
+        .filter(|value| {
+            let path = Path::new(value);
+            PROJECT_EXTENSIONS.contains(&extension(path).as_str())
+        })
+        .map(|value| base.join(value))
+        .collect()
+}
+
+fn project_dirs(project_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let entries = match fs::read_dir(project_dir) {
+        Ok(entries) => entries,
+        Err(_) => return dirs,
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let kind = extension(&path);
+        if PROJECT_EXTENSIONS.contains(&kind.as_str()) {
+            dirs.push(project_dir.to_path_buf());
+        } else if kind == "sln" {
+            dirs.extend(
+                solution_projects(&path)
+                    .iter()
+                    .filter(|project| project.is_file())
+                    .filter_map(|project| project.parent().map(Path::to_path_buf)),
+            );
+        }
+    }
+    dirs.retain(|dir| {
+        dir.starts_with(project_dir)
+            && dir
+                .components()
+                .all(|part| !matches!(part, Component::ParentDir | Component::CurDir))
+    });
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+impl ProjectDetector for DotnetDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Dotnet
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["*.sln", "*.csproj", "*.fsproj", "*.vbproj"]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        let kind = extension(Path::new(file_name));
+        kind == "sln" || PROJECT_EXTENSIONS.contains(&kind.as_str())
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut names = BTreeSet::new();
+        for path in manifests {
+            if extension(path) != "sln" {
+                package_references(path, &mut names);
+            }
+        }
+
+        let root_manifest = manifests
+            .iter()
+            .filter(|path| path.parent() == Some(project_dir))
+            .min_by_key(|path| extension(path) != "sln");
+        let name = root_manifest
+            .and_then(|path| path.file_stem())
+            .map(|stem| stem.to_string_lossy().to_string());
+
+        Some(ManifestDetails {
+            name,
+            dependency_count: names.len(),
+            keywords: Vec::new(),
+            scripts: Vec::new(),
+        })
+    }
+
+    fn workspace_members(&self, project_dir: &Path, manifests: &[PathBuf]) -> Vec<PathBuf> {
+        manifests
+            .iter()
+            .filter(|path| extension(path) == "sln")
+            .flat_map(|solution| solution_projects(solution))
+            .filter_map(|project| project.parent().map(Path::to_path_buf))
+            .filter(|dir| dir != project_dir)
+            .collect()
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        project_dirs(project_dir)
+            .iter()
+            .flat_map(|dir| existing_dirs(dir, ARTIFACT_DIRS))
+            .collect()
+    }
+}
@@ -0,0 +1,252 @@
+use super::{expand_member_pattern, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// PE files start with "MZ" too, but so does plenty of text; those are checked
+// separately through the PE header offset.
+const BINARY_MAGIC: &[&[u8]] = &[
+    b"\x7fELF",
+    b"\xcf\xfa\xed\xfe",
+    b"\xce\xfa\xed\xfe",
+    b"\xfe\xed\xfa\xcf",
+    b"\xfe\xed\xfa\xce",
+];
+
+pub struct GoDetector;
+
+fn directive_entries(data: &str, directive: &str) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut in_block = false;
+    for line in data.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if in_block {
+            if line.starts_with(')') {
+                in_block = false;
+            } else if !line.is_empty() {
+                entries.push(line.to_string());
+            }
+            continue;
+        }
+        let rest = match line.strip_prefix(directive) {
+            Some(rest) if rest.starts_with([' ', '\t', '(']) => rest.trim(),
+            _ => continue,
+        };
+        if rest.starts_with('(') {
+            in_block = true;
+            let inline = rest.trim_start_matches('(').trim();
+            if !inline.is_empty() && !inline.starts_with(')') {
+                entries.push(inline.to_string());
+            }
+        } else if !rest.is_empty() {
+            entries.push(rest.to_string());
+        }
+    }
+    entries
+}
+
+fn is_native_binary(path: &Path) -> bool {
+    let mut header = [0u8; 0x40];
+    let read = fs::File::open(path)
+        .and_then(|mut file| file.read(&mut header))
+        .unwrap_or(0);
+    if BINARY_MAGIC
+        .iter()
+        .any(|magic| read >= magic.len() && header.starts_with(magic))
+    {
+        return true;
+    }
+    read == header.len() && header.starts_with(b"MZ") && has_pe_signature(path, &header)
+}
+
+// The DOS header stores the offset of the "PE\0\0" signature at 0x3c.
+fn has_pe_signature(path: &Path, header: &[u8; 0x40]) -> bool {
+    let offset = u32::from_le_bytes([header[0x3c], header[0x3d], header[0x3e], header[0x3f]]);
+    let mut signature = [0u8; 4];
+    fs::File::open(path)
+        .and_then(|mut file| {
+            file.seek(SeekFrom::Start(u64::from(offset)))?;
+            file.read_exact(&mut signature)
+        })
+        .is_ok()
+        && &signature == b"PE\0\0"
+}
+
+fn is_main_package(dir: &Path) -> bool {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.ends_with(".go") || name.ends_with("_test.go") {
+            return false;
+        }
+        fs::read_to_string(entry.path())
+            .map(|data| {
+                data.lines()
+                    .map(|line| line.trim())
+                    .find(|line| line.starts_with("package "))
+                    .is_some_and(|line| line.split_whitespace().nth(1) == Some("main"))
+            })
+            .unwrap_or(false)
+    })
+}
+
+// `go build` names its output after the module path's last element (skipping
+// a /vN major version suffix) or after the main package's directory.
+fn binary_names(module_dir: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    let module = fs::read_to_string(module_dir.join("go.mod"))
+        .ok()
+        .and_then(|data| directive_entries(&data, "module").into_iter().next());
+    if let Some(module) = module {
+        let parts: Vec<&str> = module.trim_matches('"').split('/').collect();
+        let is_major = |part: &str| {
+            part.strip_prefix('v')
+                .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|ch| ch.is_ascii_digit()))
+        };
+        let last = match parts.as_slice() {
+            [.., name, version] if is_major(version) => Some(*name),
+            [.., name] => Some(*name),
+            [] => None,
+        };
+        names.extend(last.map(str::to_string));
+    }
+
+    let mut main_dirs: Vec<PathBuf> = Vec::new();
+    for dir in [module_dir.to_path_buf(), module_dir.join("cmd")] {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            main_dirs.extend(
+                entries
+                    .flatten()
+                    .filter(|entry| entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false))
+                    .map(|entry| entry.path()),
+            );
+        }
+    }
+    for dir in &main_dirs {
+        if is_main_package(dir) {
+            names.extend(dir.file_name().map(|name| name.to_string_lossy().to_string()));
+        }
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn in_git_repo(dir: &Path) -> bool {
+    dir.ancestors().any(|ancestor| ancestor.join(".git").exists())
+}
+
+// Checked-in binaries (vendored protoc and the like) are source, so only
+// files git does not track are offered. When git cannot answer, nothing is.
+fn untracked(module_dir: &Path, files: Vec<PathBuf>) -> Vec<PathBuf> {
+    if files.is_empty() || !in_git_repo(module_dir) {
+        return files;
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(module_dir)
+        .args(["ls-files", "-z", "--"])
+        .args(files.iter().filter_map(|path| path.file_name()))
+        .output();
+    let tracked = match output {
+        Ok(output) if output.status.success() => output.stdout,
+        _ => return Vec::new(),
+    };
+    let tracked: Vec<&[u8]> = tracked.split(|byte| *byte == 0).collect();
+    files
+        .into_iter()
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            !tracked.contains(&name.as_bytes())
+        })
+        .collect()
+}
+
+fn built_binaries(module_dir: &Path) -> Vec<PathBuf> {
+    let names = binary_names(module_dir);
+    let mut binaries: Vec<PathBuf> = names
+        .iter()
+        .flat_map(|name| [module_dir.join(name), module_dir.join(format!("{name}.exe"))])
+        .filter(|path| path.is_file() && is_native_binary(path))
+        .collect();
+    binaries.sort();
+    untracked(module_dir, binaries)
+}
+
+fn module_artifacts(module_dir: &Path) -> Vec<PathBuf> {
+    let mut artifacts = Vec::new();
+    let vendor = module_dir.join("vendor");
+    if vendor.is_dir() && module_dir.join("go.sum").is_file() {
+        artifacts.push(vendor);
+    }
+    artifacts.extend(built_binaries(module_dir));
+    artifacts
+}
+
+impl ProjectDetector for GoDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Go
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["go.mod", "go.work"]
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut details = ManifestDetails::default();
+        let mut requires: Vec<String> = Vec::new();
+
+        for path in manifests.iter().filter(|path| path.ends_with("go.mod")) {
+            let data = match fs::read_to_string(path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            for entry in directive_entries(&data, "require") {
+                let module = entry.split_whitespace().next().unwrap_or_default();
+                if !requires.iter().any(|item| item == module) {
+                    requires.push(module.to_string());
+                }
+            }
+            if path.parent() == Some(project_dir) {
+                details.name = directive_entries(&data, "module")
+                    .into_iter()
+                    .next()
+                    .map(|module| module.trim_matches('"').to_string());
+            }
+        }
+
+        details.dependency_count = requires.len();
+        Some(details)
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let data = match fs::read_to_string(project_dir.join("go.work")) {
+            Ok(data) => data,
+            Err(_) => return Vec::new(),
+        };
+        directive_entries(&data, "use")
+            .iter()
+            .flat_map(|entry| expand_member_pattern(project_dir, entry.trim_matches('"')))
+            .filter(|member| member.join("go.mod").is_file())
+            .collect()
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &["vendor"]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut artifacts = module_artifacts(project_dir);
+        for member in self.workspace_members(project_dir, &[]) {
+            if member != project_dir {
+                artifacts.extend(module_artifacts(&member));
+            }
+        }
+        artifacts
+    }
+}
@@ -0,0 +1,202 @@
+use super::{existing_dirs, expand_member_pattern, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &[".stack-work", "dist-newstyle"];
+
+pub struct HaskellDetector;
+
+fn is_cabal_file(path: &Path) -> bool {
+    path.extension().map(|ext| ext == "cabal").unwrap_or(false)
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn cabal_details(data: &str, names: &mut BTreeSet<String>) -> Option<String> {
+    let mut name = None;
+    let mut field_indent: Option<usize> = None;
+
+    for line in data.lines() {
+        let content = line.split("--").next().unwrap_or_default().trim_end();
+        if content.trim().is_empty() {
+            continue;
+        }
+        let indent = indentation(content);
+        let trimmed = content.trim();
+        let lowered = trimmed.to_lowercase();
+
+        if indent == 0 && lowered.starts_with("name:") {
+            name = Some(trimmed["name:".len()..].trim().to_string());
+        }
+
+        let values = if let Some(rest) = lowered.strip_prefix("build-depends:") {
+            field_indent = Some(indent);
+            rest.to_string()
+        } else if field_indent.is_some_and(|field| indent > field) && !trimmed.contains(':') {
+            lowered.clone()
+        } else {
+            field_indent = None;
+            continue;
+        };
+
+        for item in values.split(',') {
+            let package: String = item
+                .trim()
+                .chars()
+                .take_while(|ch| ch.is_ascii_alphanumeric() || *ch == '-')
+                .collect();
+            if !package.is_empty() {
+                names.insert(package);
+            }
+        }
+    }
+
+    name
+}
+
+fn yaml_list(data: &str, key: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut in_list = false;
+    for line in data.lines() {
+        let content = line.split('#').next().unwrap_or_default().trim_end();
+        if content.trim().is_empty() {
+            continue;
+        }
+        if indentation(content) == 0 && !content.starts_with('-') {
+            in_list = content.trim_end_matches(':') == key;
+            continue;
+        }
+        if in_list {
+            if let Some(item) = content.trim().strip_prefix('-') {
+                items.push(
+                    item.trim()
+                        .trim_matches(|ch| ch == '"' || ch == '\'')
+                        .to_string(),
+                );
+            }
+        }
+    }
+    items
+}
+
+fn cabal_project_packages(data: &str) -> Vec<String> {
+    let mut packages = Vec::new();
+    let mut in_packages = false;
+    for line in data.lines() {
+        let content = line.split("--").next().unwrap_or_default().trim_end();
+        if content.trim().is_empty() {
+            continue;
+        }
+        let rest = if let Some(rest) = content.strip_prefix("packages:") {
+            in_packages = true;
+            rest
+        } else if in_packages && indentation(content) > 0 {
+            content
+        } else {
+            in_packages = false;
+            continue;
+        };
+        packages.extend(rest.split_whitespace().map(|item| item.to_string()));
+    }
+    packages
+}
+
+fn package_dir(project_dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim_end_matches('/');
+    let pattern = if pattern.ends_with(".cabal") {
+        Path::new(pattern)
+            .parent()
+            .map(|parent| parent.to_string_lossy().to_string())
+            .unwrap_or_default()
+    } else {
+        pattern.to_string()
+    };
+    expand_member_pattern(project_dir, &pattern)
+}
+
+impl ProjectDetector for HaskellDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Haskell
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["stack.yaml", "cabal.project", "*.cabal"]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        file_name == "stack.yaml"
+            || file_name == "cabal.project"
+            || is_cabal_file(Path::new(file_name))
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut names = BTreeSet::new();
+        let mut details = ManifestDetails::default();
+
+        for path in manifests {
+            let data = match fs::read_to_string(path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            if is_cabal_file(path) {
+                let name = cabal_details(&data, &mut names);
+                if path.parent() == Some(project_dir) && name.is_some() {
+                    details.name = name;
+                }
+            } else if path.ends_with("stack.yaml") {
+                for dependency in yaml_list(&data, "extra-deps") {
+                    let package: String = dependency
+                        .chars()
+                        .take_while(|ch| ch.is_ascii_alphanumeric() || *ch == '-')
+                        .collect();
+                    let package = package
+                        .rsplit_once('-')
+                        .filter(|(_, version)| version.starts_with(|ch: char| ch.is_ascii_digit()))
+                        .map(|(name, _)| name.to_string())
+                        .unwrap_or(package);
+                    if !package.is_empty() {
+                        names.insert(package.to_lowercase());
+                    }
+                }
+            }
+        }
+
+        details.dependency_count = names.len();
+        Some(details)
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let mut patterns = Vec::new();
+        if let Ok(data) = fs::read_to_string(project_dir.join("stack.yaml")) {
+            patterns.extend(yaml_list(&data, "packages"));
+        }
+        if let Ok(data) = fs::read_to_string(project_dir.join("cabal.project")) {
+            patterns.extend(cabal_project_packages(&data));
+        }
+
+        let mut members: Vec<PathBuf> = patterns
+            .iter()
+            .flat_map(|pattern| package_dir(project_dir, pattern))
+            .filter(|member| member != project_dir)
+            .collect();
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = existing_dirs(project_dir, ARTIFACT_DIRS);
+        for member in self.workspace_members(project_dir, &[]) {
+            dirs.extend(existing_dirs(&member, ARTIFACT_DIRS));
+        }
+        dirs
+    }
+}
@@ -0,0 +1,144 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const ARTIFACT_NAMES: &[&str] = &[".terraform", ".serverless", "cdk.out", ".pulumi"];
+const ARTIFACT_DIRS: &[&str] = &[
+    ".terraform/providers",
+    ".serverless",
+    "cdk.out",
+    ".pulumi/plugins",
+];
+const SERVERLESS_FILES: &[&str] = &[
+    "serverless.yml",
+    "serverless.yaml",
+    "serverless.ts",
+    "serverless.js",
+    "serverless.json",
+];
+
+pub struct InfraDetector;
+
+fn is_terraform_file(path: &Path) -> bool {
+    path.extension().map(|ext| ext == "tf").unwrap_or(false)
+}
+
+fn quoted_value(line: &str) -> Option<String> {
+    let rest = line.split_once('=')?.1.trim();
+    let rest = rest.strip_prefix('"')?;
+    let end = rest.find('"')?;
+    Some(rest[..end].to_string())
+}
+
+fn terraform_sources(path: &Path) -> Vec<String> {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(_) => return Vec::new(),
+    };
+    data.lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| line.starts_with("source") && line.contains('='))
+        .filter_map(quoted_value)
+        .collect()
+}
+
+fn serverless_plugins(path: &Path) -> usize {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(_) => return 0,
+    };
+    let mut count = 0;
+    let mut in_plugins = false;
+    for line in data.lines() {
+        let content = line.split('#').next().unwrap_or_default().trim_end();
+        if content.trim().is_empty() {
+            continue;
+        }
+        if !content.starts_with([' ', '\t', '-']) {
+            in_plugins = content.trim_end_matches(':') == "plugins";
+            continue;
+        }
+        if in_plugins && content.trim_start().starts_with('-') {
+            count += 1;
+        }
+    }
+    count
+}
+
+impl ProjectDetector for InfraDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Infra
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &[
+            "*.tf",
+            "serverless.yml",
+            "serverless.yaml",
+            "serverless.ts",
+            "serverless.js",
+            "serverless.json",
+            "cdk.json",
+            "Pulumi.yaml",
+        ]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        is_terraform_file(Path::new(file_name))
+            || SERVERLESS_FILES.contains(&file_name)
+            || file_name == "cdk.json"
+            || file_name == "Pulumi.yaml"
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let mut sources: Vec<String> = Vec::new();
+        let mut plugins = 0usize;
+        for path in manifests {
+            if is_terraform_file(path) {
+                for source in terraform_sources(path) {
+                    if !sources.contains(&source) {
+                        sources.push(source);
+                    }
+                }
+            } else if path.ends_with("serverless.yml") || path.ends_with("serverless.yaml") {
+                plugins += serverless_plugins(path);
+            }
+        }
+
+        Some(ManifestDetails {
+            dependency_count: sources.len() + plugins,
+            ..ManifestDetails::default()
+        })
+    }
+
+    fn workspace_members(&self, project_dir: &Path, manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let mut members: Vec<PathBuf> = manifests
+            .iter()
+            .filter(|path| is_terraform_file(path))
+            .flat_map(|path| terraform_sources(path))
+            .filter(|source| source.starts_with("./"))
+            .map(|source| project_dir.join(source.trim_start_matches("./")))
+            .filter(|member| {
+                member
+                    .components()
+                    .all(|part| !matches!(part, Component::ParentDir | Component::CurDir))
+            })
+            .collect();
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_NAMES
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        existing_dirs(project_dir, ARTIFACT_DIRS)
+    }
+}
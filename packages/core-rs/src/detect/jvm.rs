@@ -0,0 +1,271 @@
+use super::{
+    existing_dirs, strip_xml_comments, xml_elements, xml_text, ManifestDetails, ProjectDetector,
+};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const MAVEN_ARTIFACT_DIRS: &[&str] = &["target"];
+const GRADLE_ARTIFACT_DIRS: &[&str] = &["build", ".gradle"];
+const GRADLE_BUILD_FILES: &[&str] = &["build.gradle", "build.gradle.kts"];
+const GRADLE_SETTINGS_FILES: &[&str] = &["settings.gradle", "settings.gradle.kts"];
+const GRADLE_CONFIGURATIONS: &[&str] = &[
+    "implementation",
+    "api",
+    "compileOnly",
+    "runtimeOnly",
+    "testImplementation",
+    "testCompileOnly",
+    "testRuntimeOnly",
+    "androidTestImplementation",
+    "debugImplementation",
+    "releaseImplementation",
+    "annotationProcessor",
+    "kapt",
+    "ksp",
+    "compile",
+    "testCompile",
+    "runtime",
+];
+
+pub struct JvmDetector;
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+fn read_pom(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|data| strip_xml_comments(&data))
+}
+
+fn pom_dependencies(pom: &str, names: &mut BTreeSet<String>) {
+    for dependency in xml_elements(pom, "dependency") {
+        let group = xml_text(dependency, "groupId").unwrap_or_default();
+        if let Some(artifact) = xml_text(dependency, "artifactId") {
+            names.insert(format!("{group}:{artifact}"));
+        }
+    }
+}
+
+fn pom_name(pom: &str) -> Option<String> {
+    let mut own = pom.to_string();
+    for tag in [
+        "parent",
+        "dependencies",
+        "dependencyManagement",
+        "build",
+        "profiles",
+    ] {
+        for element in xml_elements(pom, tag) {
+            own = own.replacen(element, "", 1);
+        }
+    }
+    xml_text(&own, "name").or_else(|| xml_text(&own, "artifactId"))
+}
+
+fn pom_modules(pom: &str) -> Vec<String> {
+    xml_elements(pom, "module")
+        .into_iter()
+        .filter_map(|element| {
+            element
+                .split_once('>')
+                .map(|(_, body)| body.trim().to_string())
+        })
+        .filter(|module| !module.is_empty())
+        .collect()
+}
+
+fn quoted_strings(line: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(['"', '\'']) {
+        let quote = rest[start..].chars().next().unwrap_or('"');
+        let after = &rest[start + 1..];
+        match after.find(quote) {
+            Some(end) => {
+                values.push(after[..end].to_string());
+                rest = &after[end + 1..];
+            }
+            None => break,
+        }
+    }
+    values
+}
+
+fn gradle_dependencies(script: &str, names: &mut BTreeSet<String>) {
+    for line in script.lines() {
+        let line = line.trim();
+        let configuration: String = line
+            .chars()
+            .take_while(|ch| ch.is_ascii_alphanumeric())
+            .collect();
+        if !GRADLE_CONFIGURATIONS.contains(&configuration.as_str()) {
+            continue;
+        }
+        let rest = line[configuration.len()..].trim_start();
+        let is_declaration = rest.starts_with(['(', '"', '\''])
+            || rest.starts_with("project")
+            || rest.starts_with("libs.");
+        if !is_declaration {
+            continue;
+        }
+        let notation = quoted_strings(rest)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| rest.trim_matches(|ch| ch == '(' || ch == ')').to_string());
+        names.insert(notation);
+    }
+}
+
+fn gradle_settings(path: &Path) -> (Option<String>, Vec<String>) {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(_) => return (None, Vec::new()),
+    };
+
+    let mut name = None;
+    let mut includes = Vec::new();
+    let mut continued = false;
+    for line in data.lines() {
+        let line = line.trim();
+        if line.starts_with("rootProject.name") {
+            name = quoted_strings(line).into_iter().next();
+            continue;
+        }
+        if continued || (line.starts_with("include") && !line.starts_with("includeBuild")) {
+            let body = line.trim_start_matches("include");
+            includes.extend(quoted_strings(body));
+            continued = line.ends_with(',');
+        }
+    }
+    (name, includes)
+}
+
+fn is_nested(project_dir: &Path, member: &Path) -> bool {
+    member != project_dir
+        && member.starts_with(project_dir)
+        && member
+            .components()
+            .all(|part| !matches!(part, Component::ParentDir | Component::CurDir))
+        && member.is_dir()
+}
+
+fn maven_members(project_dir: &Path) -> Vec<PathBuf> {
+    let pom = match read_pom(&project_dir.join("pom.xml")) {
+        Some(pom) => pom,
+        None => return Vec::new(),
+    };
+    pom_modules(&pom)
+        .iter()
+        .map(|module| project_dir.join(module.trim_end_matches("/pom.xml")))
+        .filter(|member| is_nested(project_dir, member))
+        .collect()
+}
+
+fn gradle_members(project_dir: &Path) -> Vec<PathBuf> {
+    GRADLE_SETTINGS_FILES
+        .iter()
+        .map(|name| project_dir.join(name))
+        .filter(|path| path.is_file())
+        .flat_map(|path| gradle_settings(&path).1)
+        .map(|include| {
+            let relative = include.trim_start_matches(':').replace(':', "/");
+            project_dir.join(relative)
+        })
+        .filter(|member| is_nested(project_dir, member))
+        .collect()
+}
+
+fn module_artifacts(project_dir: &Path, gradle_member: bool, dirs: &mut Vec<PathBuf>) {
+    if project_dir.join("pom.xml").is_file() {
+        dirs.extend(existing_dirs(project_dir, MAVEN_ARTIFACT_DIRS));
+    }
+    let is_gradle = gradle_member
+        || GRADLE_BUILD_FILES
+            .iter()
+            .chain(GRADLE_SETTINGS_FILES)
+            .any(|name| project_dir.join(name).is_file());
+    if is_gradle {
+        dirs.extend(existing_dirs(project_dir, GRADLE_ARTIFACT_DIRS));
+    }
+
+    for member in maven_members(project_dir) {
+        module_artifacts(&member, false, dirs);
+    }
+    for member in gradle_members(project_dir) {
+        module_artifacts(&member, true, dirs);
+    }
+}
+
+impl ProjectDetector for JvmDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Jvm
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &[
+            "pom.xml",
+            "build.gradle",
+            "build.gradle.kts",
+            "settings.gradle",
+            "settings.gradle.kts",
+        ]
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut names = BTreeSet::new();
+        let mut details = ManifestDetails::default();
+
+        for path in manifests {
+            let is_root = path.parent() == Some(project_dir);
+            let name = file_name(path);
+            if name == "pom.xml" {
+                let pom = match read_pom(path) {
+                    Some(pom) => pom,
+                    None => continue,
+                };
+                pom_dependencies(&pom, &mut names);
+                if is_root && details.name.is_none() {
+                    details.name = pom_name(&pom);
+                }
+            } else if GRADLE_SETTINGS_FILES.contains(&name.as_str()) {
+                if is_root {
+                    if let (Some(root_name), _) = gradle_settings(path) {
+                        details.name = Some(root_name);
+                    }
+                }
+            } else if let Ok(script) = fs::read_to_string(path) {
+                gradle_dependencies(&script, &mut names);
+            }
+        }
+
+        details.dependency_count = names.len();
+        Some(details)
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let mut members = maven_members(project_dir);
+        members.extend(gradle_members(project_dir));
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &["target", "build", ".gradle"]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        module_artifacts(project_dir, false, &mut dirs);
+        dirs.sort();
+        dirs.dedup();
+        dirs
+    }
+}
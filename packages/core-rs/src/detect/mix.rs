@@ -0,0 +1,118 @@
+use super::{existing_dirs, expand_member_pattern, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &["_build", "deps"];
+
+pub struct MixDetector;
+
+fn deps_block(data: &str) -> Option<&str> {
+    let start = data.find("defp deps")?;
+    let rest = &data[start..];
+    let open = rest.find('[')?;
+    let mut depth = 0usize;
+    for (index, ch) in rest[open..].char_indices() {
+        match ch {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&rest[open + 1..open + index]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn dependency_names(data: &str, names: &mut BTreeSet<String>) {
+    let block = match deps_block(data) {
+        Some(block) => block,
+        None => return,
+    };
+    for part in block.split("{:").skip(1) {
+        let name: String = part
+            .chars()
+            .take_while(|ch| ch.is_ascii_alphanumeric() || *ch == '_')
+            .collect();
+        if !name.is_empty() {
+            names.insert(name);
+        }
+    }
+}
+
+fn atom_value(data: &str, key: &str) -> Option<String> {
+    let start = data.find(key)? + key.len();
+    let rest = data[start..].trim_start();
+    let rest = rest.strip_prefix(':')?;
+    let value: String = rest
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphanumeric() || *ch == '_')
+        .collect();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn string_value(data: &str, key: &str) -> Option<String> {
+    let start = data.find(key)? + key.len();
+    let rest = data[start..].trim_start().strip_prefix('"')?;
+    let end = rest.find('"')?;
+    Some(rest[..end].to_string())
+}
+
+impl ProjectDetector for MixDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Elixir
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["mix.exs", "mix.lock"]
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut names = BTreeSet::new();
+        let mut details = ManifestDetails::default();
+
+        for path in manifests.iter().filter(|path| path.ends_with("mix.exs")) {
+            let data = match fs::read_to_string(path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            dependency_names(&data, &mut names);
+            if path.parent() == Some(project_dir) {
+                details.name = atom_value(&data, "app:");
+            }
+        }
+
+        details.dependency_count = names.len();
+        Some(details)
+    }
+
+    fn workspace_members(&self, project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        let data = match fs::read_to_string(project_dir.join("mix.exs")) {
+            Ok(data) => data,
+            Err(_) => return Vec::new(),
+        };
+        match string_value(&data, "apps_path:") {
+            Some(apps) => expand_member_pattern(project_dir, &format!("{apps}/*"))
+                .into_iter()
+                .filter(|member| member.join("mix.exs").is_file())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        existing_dirs(project_dir, ARTIFACT_DIRS)
+    }
+}
@@ -0,0 +1,286 @@
+mod bundler;
+mod cargo;
+mod composer;
+mod cpp;
+mod dart;
+mod dotnet;
+mod go;
+mod haskell;
+mod infra;
+mod jvm;
+mod mix;
+mod node;
+mod python;
+mod swift;
+mod unity;
+mod unreal;
+
+pub use bundler::BundlerDetector;
+pub use cargo::CargoDetector;
+pub use composer::ComposerDetector;
+pub use cpp::CppDetector;
+pub use dart::DartDetector;
+pub use dotnet::DotnetDetector;
+pub use go::GoDetector;
+pub use haskell::HaskellDetector;
+pub use infra::InfraDetector;
+pub use jvm::JvmDetector;
+pub use mix::MixDetector;
+pub use node::NodeDetector;
+pub use python::PythonDetector;
+pub use swift::SwiftDetector;
+pub use unity::UnityDetector;
+pub use unreal::UnrealDetector;
+
+use crate::scanner::is_ignored;
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Default)]
+pub struct ManifestDetails {
+    pub name: Option<String>,
+    pub dependency_count: usize,
+    pub keywords: Vec<String>,
+    pub scripts: Vec<String>,
+}
+
+/// Describes one ecosystem: which files mark a project, how its dependencies
+/// are counted and which of its subdirectories can be regenerated.
+pub trait ProjectDetector: Send + Sync {
+    fn ecosystem(&self) -> Ecosystem;
+
+    fn manifest_files(&self) -> &'static [&'static str];
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        self.manifest_files().contains(&file_name)
+    }
+
+    fn project_dir(&self, manifest: &Path) -> Option<PathBuf> {
+        manifest.parent().map(Path::to_path_buf)
+    }
+
+    fn find_manifests(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut manifests: Vec<PathBuf> = match fs::read_dir(project_dir) {
+            Ok(entries) => entries
+                .flatten()
+                .filter(|entry| {
+                    entry
+                        .file_type()
+                        .map(|kind| kind.is_file())
+                        .unwrap_or(false)
+                })
+                .filter(|entry| self.is_manifest(&entry.file_name().to_string_lossy()))
+                .map(|entry| entry.path())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        manifests.sort();
+        manifests
+    }
+
+    fn read_manifests(&self, project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails>;
+
+    fn workspace_members(&self, _project_dir: &Path, _manifests: &[PathBuf]) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn is_artifact_dir(&self, dir: &Path) -> bool {
+        let name = match dir.file_name() {
+            Some(name) => name.to_string_lossy(),
+            None => return false,
+        };
+        if !self.artifact_names().contains(&name.as_ref()) {
+            return false;
+        }
+        dir.parent()
+            .map(|parent| !self.find_manifests(parent).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf>;
+
+    /// Artifacts that can turn up at any depth below `project_dir`, such as
+    /// `__pycache__`. The scanner asks this for every folder it walks inside
+    /// a project, so it should stay cheap.
+    fn is_nested_artifact(&self, _project_dir: &Path, _dir: &Path) -> bool {
+        false
+    }
+}
+
+// Earlier detectors win the ecosystem tag when a directory holds several
+// manifests. package.json is often tooling-only, so Node comes late, and
+// infrastructure files usually sit beside application code, so Infra is last.
+static DETECTORS: &[&dyn ProjectDetector] = &[
+    &CargoDetector,
+    &PythonDetector,
+    &JvmDetector,
+    &GoDetector,
+    &DotnetDetector,
+    &SwiftDetector,
+    &DartDetector,
+    &ComposerDetector,
+    &BundlerDetector,
+    &MixDetector,
+    &HaskellDetector,
+    &CppDetector,
+    &UnityDetector,
+    &UnrealDetector,
+    &NodeDetector,
+    &InfraDetector,
+];
+
+pub fn detectors() -> &'static [&'static dyn ProjectDetector] {
+    DETECTORS
+}
+
+// Without a scan to collect them, nested artifacts need a walk of their own.
+fn nested_artifact_dirs(detector: &dyn ProjectDetector, project_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut walker = WalkDir::new(project_dir).follow_links(false).into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        if entry.depth() == 0 || !entry.file_type().is_dir() {
+            continue;
+        }
+        if detector.is_nested_artifact(project_dir, entry.path()) {
+            dirs.push(entry.path().to_path_buf());
+            walker.skip_current_dir();
+        } else if is_ignored(entry.path(), entry.depth(), false) {
+            walker.skip_current_dir();
+        }
+    }
+    dirs
+}
+
+pub fn detect_artifacts(project_dir: &Path) -> Vec<PathBuf> {
+    let mut artifacts: Vec<PathBuf> = Vec::new();
+    for detector in detectors() {
+        if detector.find_manifests(project_dir).is_empty() {
+            continue;
+        }
+        let nested = nested_artifact_dirs(*detector, project_dir);
+        for artifact in detector.artifact_dirs(project_dir).into_iter().chain(nested) {
+            if !artifacts.contains(&artifact) {
+                artifacts.push(artifact);
+            }
+        }
+    }
+    artifacts
+}
+
+pub fn is_project_artifact(dir: &Path) -> bool {
+    detectors()
+        .iter()
+        .any(|detector| detector.is_artifact_dir(dir))
+}
+
+pub(crate) fn expand_member_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut current = vec![root.to_path_buf()];
+    for segment in pattern.split(['/', '\\']) {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        let mut next = Vec::new();
+        for base in &current {
+            if !segment.contains('*') {
+                next.push(base.join(segment));
+                continue;
+            }
+            let (prefix, suffix) = segment.split_once('*').unwrap_or((segment, ""));
+            let suffix = suffix.trim_start_matches('*');
+            if let Ok(entries) = fs::read_dir(base) {
+                for entry in entries.flatten() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    let is_dir = entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false);
+                    if is_dir && name.starts_with(prefix) && name.ends_with(suffix) {
+                        next.push(entry.path());
+                    }
+                }
+            }
+        }
+        current = next;
+    }
+    current.retain(|path| path.is_dir());
+    current.sort();
+    current
+}
+
+pub(crate) fn strip_xml_comments(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("<!--") {
+        output.push_str(&rest[..start]);
+        rest = match rest[start..].find("-->") {
+            Some(end) => &rest[start + end + 3..],
+            None => "",
+        };
+    }
+    output.push_str(rest);
+    output
+}
+
+pub(crate) fn xml_elements<'a>(text: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        let boundary = after.chars().next().unwrap_or('>');
+        if !(boundary.is_whitespace() || boundary == '>' || boundary == '/') {
+            rest = after;
+            continue;
+        }
+        let head_end = match after.find('>') {
+            Some(index) => index,
+            None => break,
+        };
+        if after[..head_end].ends_with('/') {
+            elements.push(&after[..head_end]);
+            rest = &after[head_end + 1..];
+            continue;
+        }
+        match after.find(&close) {
+            Some(end) => {
+                elements.push(&after[..end]);
+                rest = &after[end + close.len()..];
+            }
+            None => {
+                elements.push(&after[..head_end]);
+                rest = &after[head_end + 1..];
+            }
+        }
+    }
+    elements
+}
+
+pub(crate) fn xml_text(element: &str, tag: &str) -> Option<String> {
+    let inner = xml_elements(element, tag).into_iter().next()?;
+    let value = inner
+        .split_once('>')
+        .map(|(_, body)| body)
+        .unwrap_or(inner)
+        .trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+pub(crate) fn existing_dirs(project_dir: &Path, names: &[&str]) -> Vec<PathBuf> {
+    names
+        .iter()
+        .map(|name| project_dir.join(name))
+        .filter(|path| path.exists())
+        .collect()
+}
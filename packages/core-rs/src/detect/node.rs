@@ -0,0 +1,300 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &["node_modules", ".cache"];
+const DEPENDENCY_KEYS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+const OUT_DIR_FLAGS: &[&str] = &["--outDir", "--out-dir", "--outdir", "--output-path"];
+
+struct FrameworkCache {
+    // Entries ending in '/' match every package under that scope.
+    packages: &'static [&'static str],
+    dirs: &'static [&'static str],
+}
+
+struct BuildTool {
+    command: &'static str,
+    // Required first argument, for CLIs that do more than build.
+    subcommand: Option<&'static str>,
+    output: &'static str,
+}
+
+// Default output folders of build tools. Folders that merely share these
+// names (electron-builder's build/ holds icons and entitlements) are source,
+// so a folder is only offered when the build script runs the tool that writes
+// it, or names it through an --outDir-style flag.
+const BUILD_TOOLS: &[BuildTool] = &[
+    BuildTool {
+        command: "vite",
+        subcommand: Some("build"),
+        output: "dist",
+    },
+    BuildTool {
+        command: "react-scripts",
+        subcommand: Some("build"),
+        output: "build",
+    },
+    BuildTool {
+        command: "vue-cli-service",
+        subcommand: Some("build"),
+        output: "dist",
+    },
+    BuildTool {
+        command: "ng",
+        subcommand: Some("build"),
+        output: "dist",
+    },
+    BuildTool {
+        command: "parcel",
+        subcommand: Some("build"),
+        output: "dist",
+    },
+    BuildTool {
+        command: "astro",
+        subcommand: Some("build"),
+        output: "dist",
+    },
+    BuildTool {
+        command: "gatsby",
+        subcommand: Some("build"),
+        output: "public",
+    },
+    BuildTool {
+        command: "webpack",
+        subcommand: None,
+        output: "dist",
+    },
+    BuildTool {
+        command: "electron-builder",
+        subcommand: None,
+        output: "dist",
+    },
+];
+
+const FRAMEWORK_CACHES: &[FrameworkCache] = &[
+    FrameworkCache {
+        packages: &["next"],
+        dirs: &[".next"],
+    },
+    FrameworkCache {
+        packages: &["nuxt", "nuxt3"],
+        dirs: &[".nuxt"],
+    },
+    FrameworkCache {
+        packages: &["@sveltejs/kit"],
+        dirs: &[".svelte-kit"],
+    },
+    FrameworkCache {
+        packages: &["turbo"],
+        dirs: &[".turbo"],
+    },
+    FrameworkCache {
+        packages: &["parcel", "parcel-bundler"],
+        dirs: &[".parcel-cache"],
+    },
+    FrameworkCache {
+        packages: &["@angular/cli", "@angular-devkit/build-angular"],
+        dirs: &[".angular/cache"],
+    },
+    FrameworkCache {
+        packages: &["vite"],
+        dirs: &[".vite"],
+    },
+    FrameworkCache {
+        packages: &["storybook", "@storybook/"],
+        dirs: &["storybook-static"],
+    },
+    FrameworkCache {
+        packages: &["expo"],
+        dirs: &[".expo"],
+    },
+];
+
+pub struct NodeDetector;
+
+fn read_package_json(path: &Path) -> Option<serde_json::Value> {
+    let data = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+fn get_dependency_count(pkg: &serde_json::Value) -> usize {
+    DEPENDENCY_KEYS
+        .iter()
+        .filter_map(|key| pkg.get(key))
+        .filter_map(|value| value.as_object())
+        .map(|map| map.len())
+        .sum()
+}
+
+fn dependency_names(pkg: &serde_json::Value) -> BTreeSet<&str> {
+    DEPENDENCY_KEYS
+        .iter()
+        .filter_map(|key| pkg.get(key))
+        .filter_map(|value| value.as_object())
+        .flat_map(|map| map.keys().map(String::as_str))
+        .collect()
+}
+
+fn uses_package(dependencies: &BTreeSet<&str>, package: &str) -> bool {
+    if package.ends_with('/') {
+        dependencies.iter().any(|name| name.starts_with(package))
+    } else {
+        dependencies.contains(package)
+    }
+}
+
+fn framework_dirs(pkg: &serde_json::Value) -> Vec<&'static str> {
+    let dependencies = dependency_names(pkg);
+    FRAMEWORK_CACHES
+        .iter()
+        .filter(|cache| {
+            cache
+                .packages
+                .iter()
+                .any(|package| uses_package(&dependencies, package))
+        })
+        .flat_map(|cache| cache.dirs.iter().copied())
+        .collect()
+}
+
+fn is_inside_project(relative: &str) -> bool {
+    let path = Path::new(relative);
+    !relative.is_empty()
+        && path
+            .components()
+            .all(|part| matches!(part, Component::Normal(_) | Component::CurDir))
+        && path
+            .components()
+            .any(|part| matches!(part, Component::Normal(_)))
+}
+
+fn build_output_dirs(pkg: &serde_json::Value) -> Vec<String> {
+    let script = match pkg
+        .get("scripts")
+        .and_then(|scripts| scripts.get("build"))
+        .and_then(|value| value.as_str())
+    {
+        Some(script) => script,
+        None => return Vec::new(),
+    };
+
+    let mut dirs: Vec<String> = Vec::new();
+    let words: Vec<&str> = script.split_whitespace().collect();
+    for (index, word) in words.iter().enumerate() {
+        let command = word.rsplit('/').next().unwrap_or(word);
+        for tool in BUILD_TOOLS.iter().filter(|tool| tool.command == command) {
+            let runs_build = match tool.subcommand {
+                Some(subcommand) => words.get(index + 1) == Some(&subcommand),
+                None => true,
+            };
+            if runs_build && !dirs.iter().any(|dir| dir == tool.output) {
+                dirs.push(tool.output.to_string());
+            }
+        }
+    }
+
+    let mut words = words.into_iter();
+    while let Some(word) = words.next() {
+        let value = match word.split_once('=') {
+            Some((flag, value)) if OUT_DIR_FLAGS.contains(&flag) => Some(value),
+            _ if OUT_DIR_FLAGS.contains(&word) => words.next(),
+            _ => None,
+        };
+        let value = match value {
+            Some(value) => value
+                .trim_matches(|ch| ch == '"' || ch == '\'')
+                .trim_end_matches('/'),
+            None => continue,
+        };
+        if is_inside_project(value) && !dirs.iter().any(|dir| dir == value) {
+            dirs.push(value.to_string());
+        }
+    }
+    dirs
+}
+
+impl ProjectDetector for NodeDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Node
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["package.json"]
+    }
+
+    fn read_manifests(&self, _project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let pkg = manifests.iter().find_map(|path| read_package_json(path))?;
+
+        let name = pkg
+            .get("name")
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| value.to_string());
+
+        let keywords = pkg
+            .get("keywords")
+            .and_then(|value| value.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(|value| value.to_string()))
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        let scripts = pkg
+            .get("scripts")
+            .and_then(|value| value.as_object())
+            .map(|map| {
+                map.values()
+                    .filter_map(|value| value.as_str().map(|value| value.to_string()))
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        Some(ManifestDetails {
+            name,
+            dependency_count: get_dependency_count(&pkg),
+            keywords,
+            scripts,
+        })
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &[
+            "build",
+            ".nuxt",
+            ".svelte-kit",
+            ".turbo",
+            ".parcel-cache",
+            ".angular",
+            ".vite",
+            "storybook-static",
+            ".expo",
+        ]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = existing_dirs(project_dir, ARTIFACT_DIRS);
+        let pkg = match read_package_json(&project_dir.join("package.json")) {
+            Some(pkg) => pkg,
+            None => return dirs,
+        };
+
+        dirs.extend(existing_dirs(project_dir, &framework_dirs(&pkg)));
+        for output in build_output_dirs(&pkg) {
+            let path = project_dir.join(output);
+            if path.is_dir() && !dirs.contains(&path) {
+                dirs.push(path);
+            }
+        }
+        dirs
+    }
+}
@@ -0,0 +1,249 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const VIRTUALENV_DIRS: &[&str] = &[".venv", "venv"];
+const TOOL_CACHE_DIRS: &[&str] = &[
+    ".tox",
+    ".nox",
+    ".pytest_cache",
+    ".mypy_cache",
+    ".ruff_cache",
+];
+const ARTIFACT_NAMES: &[&str] = &[
+    ".venv",
+    "venv",
+    ".tox",
+    ".nox",
+    ".pytest_cache",
+    ".mypy_cache",
+    ".ruff_cache",
+    "__pycache__",
+];
+const BYTECODE_SKIP: &[&str] = &[".git", "node_modules", "site-packages"];
+
+pub struct PythonDetector;
+
+fn requirement_name(spec: &str) -> Option<String> {
+    let name: String = spec
+        .trim()
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+        .collect();
+    if name.is_empty() {
+        return None;
+    }
+    Some(name.to_lowercase().replace('_', "-"))
+}
+
+fn read_toml(path: &Path) -> Option<toml::Table> {
+    let data = fs::read_to_string(path).ok()?;
+    data.parse::<toml::Table>().ok()
+}
+
+fn table_keys(value: Option<&toml::Value>, names: &mut BTreeSet<String>) {
+    if let Some(table) = value.and_then(|value| value.as_table()) {
+        names.extend(
+            table
+                .keys()
+                .filter(|key| key.as_str() != "python")
+                .filter_map(|key| requirement_name(key)),
+        );
+    }
+}
+
+fn array_specs(value: Option<&toml::Value>, names: &mut BTreeSet<String>) {
+    if let Some(items) = value.and_then(|value| value.as_array()) {
+        names.extend(
+            items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .filter_map(requirement_name),
+        );
+    }
+}
+
+fn read_pyproject(path: &Path, names: &mut BTreeSet<String>, details: &mut ManifestDetails) {
+    let table = match read_toml(path) {
+        Some(table) => table,
+        None => return,
+    };
+
+    let project = table.get("project").and_then(|value| value.as_table());
+    let poetry = table
+        .get("tool")
+        .and_then(|value| value.get("poetry"))
+        .and_then(|value| value.as_table());
+
+    if let Some(project) = project {
+        array_specs(project.get("dependencies"), names);
+        if let Some(extras) = project
+            .get("optional-dependencies")
+            .and_then(|value| value.as_table())
+        {
+            for group in extras.values() {
+                array_specs(Some(group), names);
+            }
+        }
+    }
+
+    if let Some(groups) = table.get("dependency-groups").and_then(|value| value.as_table()) {
+        for group in groups.values() {
+            array_specs(Some(group), names);
+        }
+    }
+
+    if let Some(poetry) = poetry {
+        table_keys(poetry.get("dependencies"), names);
+        table_keys(poetry.get("dev-dependencies"), names);
+        if let Some(groups) = poetry.get("group").and_then(|value| value.as_table()) {
+            for group in groups.values() {
+                table_keys(group.get("dependencies"), names);
+            }
+        }
+    }
+
+    let name = project
+        .and_then(|project| project.get("name"))
+        .or_else(|| poetry.and_then(|poetry| poetry.get("name")))
+        .and_then(|value| value.as_str())
+        .filter(|value| !value.trim().is_empty());
+    if let Some(name) = name {
+        details.name = Some(name.to_string());
+    }
+
+    let keywords = project
+        .and_then(|project| project.get("keywords"))
+        .or_else(|| poetry.and_then(|poetry| poetry.get("keywords")))
+        .and_then(|value| value.as_array());
+    if let Some(keywords) = keywords {
+        details.keywords.extend(
+            keywords
+                .iter()
+                .filter_map(|item| item.as_str().map(|value| value.to_string())),
+        );
+    }
+}
+
+fn read_requirements(path: &Path, names: &mut BTreeSet<String>) {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(_) => return,
+    };
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() || line.starts_with('-') {
+            continue;
+        }
+        if let Some(name) = requirement_name(line) {
+            names.insert(name);
+        }
+    }
+}
+
+fn read_pipfile(path: &Path, names: &mut BTreeSet<String>) {
+    if let Some(table) = read_toml(path) {
+        table_keys(table.get("packages"), names);
+        table_keys(table.get("dev-packages"), names);
+    }
+}
+
+fn read_setup_py(path: &Path, names: &mut BTreeSet<String>) {
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(_) => return,
+    };
+    let start = match data.find("install_requires") {
+        Some(index) => index,
+        None => return,
+    };
+    let rest = &data[start..];
+    let open = match rest.find('[') {
+        Some(index) => index,
+        None => return,
+    };
+    let close = rest[open..].find(']').map(|index| open + index).unwrap_or(rest.len());
+    for item in rest[open + 1..close].split(',') {
+        let item = item.trim().trim_matches(|ch| ch == '"' || ch == '\'');
+        if let Some(name) = requirement_name(item) {
+            names.insert(name);
+        }
+    }
+}
+
+fn is_virtualenv(dir: &Path) -> bool {
+    dir.join("pyvenv.cfg").is_file()
+}
+
+impl ProjectDetector for PythonDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Python
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["pyproject.toml", "setup.py", "Pipfile", "requirements.txt"]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        self.manifest_files().contains(&file_name)
+            || (file_name.starts_with("requirements") && file_name.ends_with(".txt"))
+    }
+
+    fn read_manifests(&self, _project_dir: &Path, manifests: &[PathBuf]) -> Option<ManifestDetails> {
+        let mut names = BTreeSet::new();
+        let mut details = ManifestDetails::default();
+
+        for path in manifests {
+            let file_name = path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            match file_name.as_str() {
+                "pyproject.toml" => read_pyproject(path, &mut names, &mut details),
+                "Pipfile" => read_pipfile(path, &mut names),
+                "setup.py" => read_setup_py(path, &mut names),
+                _ => read_requirements(path, &mut names),
+            }
+        }
+
+        details.dependency_count = names.len();
+        Some(details)
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_NAMES
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = existing_dirs(project_dir, VIRTUALENV_DIRS)
+            .into_iter()
+            .filter(|dir| is_virtualenv(dir))
+            .collect();
+        dirs.extend(existing_dirs(project_dir, TOOL_CACHE_DIRS));
+        dirs
+    }
+
+    fn is_nested_artifact(&self, project_dir: &Path, dir: &Path) -> bool {
+        if dir.file_name().is_none_or(|name| name != "__pycache__") {
+            return false;
+        }
+        let parents = match dir.parent().map(|parent| parent.strip_prefix(project_dir)) {
+            Some(Ok(parents)) => parents,
+            _ => return false,
+        };
+        let skipped = parents.components().enumerate().any(|(depth, part)| {
+            let name = part.as_os_str().to_string_lossy();
+            (depth == 0 && ARTIFACT_NAMES.contains(&name.as_ref()))
+                || BYTECODE_SKIP.contains(&name.as_ref())
+        });
+        !skipped
+            && !dir
+                .ancestors()
+                .skip(1)
+                .take_while(|ancestor| *ancestor != project_dir)
+                .any(is_virtualenv)
+    }
+}
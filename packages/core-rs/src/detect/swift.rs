@@ -0,0 +1,100 @@
+use super::{ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct SwiftDetector;
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+fn package_swift_details(data: &str) -> (Option<String>, usize) {
+    let dependencies = data.matches(".package(").count();
+    let name = data.find("Package(").and_then(|start| {
+        let rest = &data[start..];
+        let rest = &rest[rest.find("name:")? + "name:".len()..];
+        let rest = &rest[rest.find('"')? + 1..];
+        let end = rest.find('"')?;
+        Some(rest[..end].to_string())
+    });
+    (name, dependencies)
+}
+
+fn count_lines(data: &str, prefixes: &[&str]) -> usize {
+    data.lines()
+        .map(|line| line.trim())
+        .filter(|line| prefixes.iter().any(|prefix| line.starts_with(prefix)))
+        .count()
+}
+
+fn artifact_if(project_dir: &Path, manifest: &str, artifact: &[&str]) -> Option<PathBuf> {
+    if !project_dir.join(manifest).is_file() {
+        return None;
+    }
+    let path = artifact
+        .iter()
+        .fold(project_dir.to_path_buf(), |path, part| path.join(part));
+    if path.is_dir() {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+impl ProjectDetector for SwiftDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Swift
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["Package.swift", "Podfile", "Podfile.lock", "Cartfile"]
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let mut details = ManifestDetails::default();
+
+        for path in manifests {
+            let data = match fs::read_to_string(path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            match file_name(path).as_str() {
+                "Package.swift" => {
+                    let (name, dependencies) = package_swift_details(&data);
+                    details.name = name;
+                    details.dependency_count += dependencies;
+                }
+                "Podfile" => details.dependency_count += count_lines(&data, &["pod "]),
+                "Cartfile" => {
+                    details.dependency_count += count_lines(&data, &["github ", "git ", "binary "])
+                }
+                _ => {}
+            }
+        }
+
+        Some(details)
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        &[".build", "Pods", "Carthage"]
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        [
+            artifact_if(project_dir, "Package.swift", &[".build"]),
+            artifact_if(project_dir, "Podfile", &["Pods"]),
+            artifact_if(project_dir, "Cartfile", &["Carthage", "Build"]),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
@@ -0,0 +1,90 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const VERSION_FILE: &str = "ProjectVersion.txt";
+const SETTINGS_DIR: &str = "ProjectSettings";
+const ARTIFACT_DIRS: &[&str] = &["Library", "Temp", "Obj", "Logs"];
+
+pub struct UnityDetector;
+
+fn product_name(project_dir: &Path) -> Option<String> {
+    let data =
+        fs::read_to_string(project_dir.join(SETTINGS_DIR).join("ProjectSettings.asset")).ok()?;
+    data.lines()
+        .filter_map(|line| line.trim().strip_prefix("productName:"))
+        .map(|value| value.trim().trim_matches(|ch| ch == '"' || ch == '\''))
+        .find(|value| !value.is_empty())
+        .map(|value| value.to_string())
+}
+
+fn package_count(project_dir: &Path) -> usize {
+    fs::read_to_string(project_dir.join("Packages").join("manifest.json"))
+        .ok()
+        .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+        .and_then(|manifest| {
+            manifest
+                .get("dependencies")
+                .and_then(|value| value.as_object())
+                .map(|map| map.len())
+        })
+        .unwrap_or(0)
+}
+
+impl ProjectDetector for UnityDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Unity
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["ProjectSettings/ProjectVersion.txt"]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        file_name == VERSION_FILE
+    }
+
+    fn project_dir(&self, manifest: &Path) -> Option<PathBuf> {
+        let settings = manifest.parent()?;
+        if settings.file_name()? != SETTINGS_DIR {
+            return None;
+        }
+        let project_dir = settings.parent()?;
+        if project_dir.join("Assets").is_dir() {
+            Some(project_dir.to_path_buf())
+        } else {
+            None
+        }
+    }
+
+    fn find_manifests(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let manifest = project_dir.join(SETTINGS_DIR).join(VERSION_FILE);
+        if manifest.is_file() && project_dir.join("Assets").is_dir() {
+            vec![manifest]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn read_manifests(
+        &self,
+        project_dir: &Path,
+        _manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        Some(ManifestDetails {
+            name: product_name(project_dir),
+            dependency_count: package_count(project_dir),
+            keywords: Vec::new(),
+            scripts: Vec::new(),
+        })
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        existing_dirs(project_dir, ARTIFACT_DIRS)
+    }
+}
@@ -0,0 +1,99 @@
+use super::{existing_dirs, ManifestDetails, ProjectDetector};
+use crate::types::Ecosystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ARTIFACT_DIRS: &[&str] = &["Intermediate", "Saved", "DerivedDataCache", "Binaries"];
+const PLUGIN_ARTIFACT_DIRS: &[&str] = &["Intermediate", "Binaries"];
+
+pub struct UnrealDetector;
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .map(|value| value.eq_ignore_ascii_case(extension))
+        .unwrap_or(false)
+}
+
+fn enabled_plugins(uproject: &serde_json::Value) -> usize {
+    uproject
+        .get("Plugins")
+        .and_then(|value| value.as_array())
+        .map(|plugins| {
+            plugins
+                .iter()
+                .filter(|plugin| {
+                    plugin
+                        .get("Enabled")
+                        .and_then(|value| value.as_bool())
+                        .unwrap_or(true)
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+fn plugin_dirs(project_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = match fs::read_dir(project_dir.join("Plugins")) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    dirs.sort();
+    dirs
+}
+
+impl ProjectDetector for UnrealDetector {
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Unreal
+    }
+
+    fn manifest_files(&self) -> &'static [&'static str] {
+        &["*.uproject"]
+    }
+
+    fn is_manifest(&self, file_name: &str) -> bool {
+        has_extension(Path::new(file_name), "uproject")
+    }
+
+    fn read_manifests(
+        &self,
+        _project_dir: &Path,
+        manifests: &[PathBuf],
+    ) -> Option<ManifestDetails> {
+        let manifest = manifests.first()?;
+        let dependency_count = fs::read_to_string(manifest)
+            .ok()
+            .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+            .map(|uproject| enabled_plugins(&uproject))
+            .unwrap_or(0);
+
+        Some(ManifestDetails {
+            name: manifest
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string()),
+            dependency_count,
+            keywords: Vec::new(),
+            scripts: Vec::new(),
+        })
+    }
+
+    fn artifact_names(&self) -> &'static [&'static str] {
+        ARTIFACT_DIRS
+    }
+
+    fn artifact_dirs(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = existing_dirs(project_dir, ARTIFACT_DIRS);
+        // Marketplace plugins ship prebuilt Binaries/ without Source/, so
+        // only plugins that can be rebuilt offer their build folders.
+        for plugin in plugin_dirs(project_dir)
+            .into_iter()
+            .filter(|plugin| plugin.join("Source").is_dir())
+        {
+            dirs.extend(existing_dirs(&plugin, PLUGIN_ARTIFACT_DIRS));
+        }
+        dirs
+    }
+}
@@ -1,13 +1,19 @@
 pub mod cache;
 pub mod ai;
+mod caches;
+pub mod detect;
 pub mod delete;
+pub mod prune;
 pub mod risk;
 pub mod scanner;
 pub mod types;
+mod walk;
 
 pub use cache::*;
 pub use ai::*;
+pub use detect::*;
 pub use delete::*;
+pub use prune::*;
 pub use risk::*;
 pub use scanner::*;
 pub use types::*;
@@ -0,0 +1,588 @@
+use crate::delete::{remove_path, DeleteEntry};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// `max_age_days` applies to npm's cache. `project_paths` lists the scanned
+/// projects whose `node_modules` keep pnpm store packages alive, and
+/// `excluded_paths` the projects whose hard links into the store are about to
+/// be deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneOptions {
+    pub max_age_days: u64,
+    #[serde(default)]
+    pub project_paths: Vec<PathBuf>,
+    #[serde(default)]
+    pub excluded_paths: Vec<PathBuf>,
+}
+
+impl PruneOptions {
+    /// Drops the projects being deleted, since removing them (or their
+    /// `node_modules`) releases their references into the store. Their links
+    /// are discounted up front so the plan does not depend on whether the
+    /// prune runs before or after those deletions.
+    pub fn excluding(&self, entries: &[DeleteEntry]) -> Self {
+        let mut excluded_paths = self.excluded_paths.clone();
+        excluded_paths.extend(
+            entries
+                .iter()
+                .filter(|entry| !entry.is_cache)
+                .map(|entry| entry.path.clone()),
+        );
+        Self {
+            max_age_days: self.max_age_days,
+            project_paths: self
+                .project_paths
+                .iter()
+                .filter(|path| !entries.iter().any(|entry| entry.path == **path))
+                .cloned()
+                .collect(),
+            excluded_paths,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneSummary {
+    pub entry_count: usize,
+    pub pruned_entries: usize,
+    pub reclaimed_bytes: u64,
+}
+
+struct IndexEntry {
+    bucket: PathBuf,
+    content: PathBuf,
+    time_ms: u64,
+}
+
+struct NpmCache {
+    root: PathBuf,
+    entries: HashMap<String, IndexEntry>,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+fn system_time_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis() as u64
+}
+
+fn cacache_root(path: &Path) -> Option<PathBuf> {
+    [path.join("_cacache"), path.to_path_buf()]
+        .into_iter()
+        .find(|root| root.join("index-v5").is_dir())
+}
+
+// "sha512-<base64>" maps to content-v2/sha512/ab/cd/<rest of the hex digest>.
+fn content_path(root: &Path, integrity: &str) -> Option<PathBuf> {
+    let (algorithm, digest) = integrity.split_whitespace().next()?.split_once('-')?;
+    let digest = digest.split('?').next().unwrap_or(digest);
+    let hex = hex::encode(STANDARD.decode(digest).ok()?);
+    if hex.len() < 5 {
+        return None;
+    }
+    Some(
+        root.join("content-v2")
+            .join(algorithm)
+            .join(&hex[..2])
+            .join(&hex[2..4])
+            .join(&hex[4..]),
+    )
+}
+
+fn bucket_lines(data: &str) -> impl Iterator<Item = (&str, serde_json::Value)> {
+    data.lines().filter_map(|line| {
+        let (_, json) = line.split_once('\t')?;
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        Some((line, value))
+    })
+}
+
+fn read_npm_cache(root: &Path) -> NpmCache {
+    let mut entries = HashMap::new();
+    let buckets = WalkDir::new(root.join("index-v5"))
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file());
+
+    for bucket in buckets {
+        let data = match fs::read_to_string(bucket.path()) {
+            Ok(data) => data,
+            Err(_) => continue,
+        };
+        // Buckets are append-only, so the last line for a key wins and a null
+        // integrity marks the key as removed.
+        for (_, value) in bucket_lines(&data) {
+            let key = match value.get("key").and_then(|key| key.as_str()) {
+                Some(key) => key.to_string(),
+                None => continue,
+            };
+            let content = value
+                .get("integrity")
+                .and_then(|integrity| integrity.as_str())
+                .and_then(|integrity| content_path(root, integrity));
+            let time_ms = value
+                .get("time")
+                .and_then(|time| time.as_u64())
+                .unwrap_or(0);
+            match content {
+                Some(content) => {
+                    entries.insert(
+                        key,
+                        IndexEntry {
+                            bucket: bucket.path().to_path_buf(),
+                            content,
+                            time_ms,
+                        },
+                    );
+                }
+                None => {
+                    entries.remove(&key);
+                }
+            }
+        }
+    }
+
+    NpmCache {
+        root: root.to_path_buf(),
+        entries,
+    }
+}
+
+fn last_touched_ms(path: &Path) -> u64 {
+    let meta = match fs::metadata(path) {
+        Ok(meta) => meta,
+        Err(_) => return 0,
+    };
+    [meta.modified().ok(), meta.accessed().ok()]
+        .into_iter()
+        .flatten()
+        .map(system_time_ms)
+        .max()
+        .unwrap_or(0)
+}
+
+fn content_files(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root.join("content-v2"))
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+struct NpmPrune {
+    stale_keys: HashSet<String>,
+    stale_content: Vec<PathBuf>,
+    summary: PruneSummary,
+}
+
+// A blob is stale when every index entry pointing at it, and the blob file
+// itself, were last touched before the cutoff. Blobs no entry points at are
+// always stale, as with `npm cache verify`.
+fn plan_npm(cache: &NpmCache, options: &PruneOptions) -> NpmPrune {
+    let cutoff = now_ms().saturating_sub(options.max_age_days.saturating_mul(DAY_MS));
+    let mut newest: HashMap<&Path, u64> = HashMap::new();
+    for entry in cache.entries.values() {
+        let time = newest.entry(entry.content.as_path()).or_insert(0);
+        *time = (*time).max(entry.time_ms);
+    }
+
+    let mut stale_content = Vec::new();
+    let mut reclaimed_bytes = 0u64;
+    for path in content_files(&cache.root) {
+        let indexed = newest.get(path.as_path()).copied();
+        let is_stale = match indexed {
+            Some(time) => time.max(last_touched_ms(&path)) < cutoff,
+            None => true,
+        };
+        if is_stale {
+            reclaimed_bytes += fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+            stale_content.push(path);
+        }
+    }
+
+    let stale_set: HashSet<&PathBuf> = stale_content.iter().collect();
+    let stale_keys: HashSet<String> = cache
+        .entries
+        .iter()
+        .filter(|(_, entry)| stale_set.contains(&entry.content) || !entry.content.is_file())
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    NpmPrune {
+        summary: PruneSummary {
+            entry_count: cache.entries.len(),
+            pruned_entries: stale_keys.len(),
+            reclaimed_bytes,
+        },
+        stale_keys,
+        stale_content,
+    }
+}
+
+// Rewrites each touched bucket with only the latest line of every key that
+// survives, dropping the bucket once it is empty.
+fn rewrite_buckets(cache: &NpmCache, stale_keys: &HashSet<String>) -> io::Result<()> {
+    let buckets: HashSet<&PathBuf> = cache
+        .entries
+        .iter()
+        .filter(|(key, _)| stale_keys.contains(*key))
+        .map(|(_, entry)| &entry.bucket)
+        .collect();
+
+    for bucket in buckets {
+        let data = fs::read_to_string(bucket)?;
+        let mut kept: Vec<(String, String)> = Vec::new();
+        for (line, value) in bucket_lines(&data) {
+            let key = match value.get("key").and_then(|key| key.as_str()) {
+                Some(key) => key,
+                None => continue,
+            };
+            kept.retain(|(existing, _)| existing != key);
+            if !stale_keys.contains(key) && cache.entries.contains_key(key) {
+                kept.push((key.to_string(), line.to_string()));
+            }
+        }
+        if kept.is_empty() {
+            remove_path(bucket)?;
+        } else {
+            let mut output = String::new();
+            for (_, line) in kept {
+                output.push('\n');
+                output.push_str(&line);
+            }
+            fs::write(bucket, output)?;
+        }
+    }
+    Ok(())
+}
+
+struct PnpmPackage {
+    index: PathBuf,
+    id: Option<String>,
+    contents: Vec<PathBuf>,
+}
+
+struct PnpmPrune {
+    files: Vec<PathBuf>,
+    summary: PruneSummary,
+}
+
+// A v10 store keeps package indexes in index/ beside files/, while v3 keeps
+// them among the content as files/xx/<hash>-index.json. A bare files/ folder
+// is not enough to call something a pnpm store.
+fn is_pnpm_store(root: &Path) -> bool {
+    let files = root.join("files");
+    if !files.is_dir() {
+        return false;
+    }
+    match root.file_name().and_then(|name| name.to_str()) {
+        Some("v10") => root.join("index").is_dir(),
+        Some("v3") => has_v3_index(root),
+        _ => false,
+    }
+}
+
+fn has_v3_index(root: &Path) -> bool {
+    fs::read_dir(root.join("files"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|bucket| fs::read_dir(bucket.path()).ok())
+        .flat_map(|entries| entries.flatten())
+        .any(|entry| is_index_file(root, &entry.path()))
+}
+
+fn pnpm_store_root(path: &Path) -> Option<PathBuf> {
+    ["v10", "v3"]
+        .iter()
+        .map(|version| path.join(version))
+        .chain([path.to_path_buf()])
+        .find(|root| is_pnpm_store(root))
+}
+
+// Hard links to each store file, keyed by device and inode, that are held
+// by the node_modules of projects being deleted.
+type HeldLinks = HashMap<(u64, u64), u64>;
+
+#[cfg(unix)]
+fn held_links(project_paths: &[PathBuf]) -> HeldLinks {
+    use std::os::unix::fs::MetadataExt;
+    let mut links = HeldLinks::new();
+    for project in project_paths {
+        let files = WalkDir::new(project.join("node_modules"))
+            .follow_links(false)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file());
+        for file in files {
+            if let Ok(meta) = file.metadata() {
+                if meta.nlink() > 1 {
+                    *links.entry((meta.dev(), meta.ino())).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    links
+}
+
+#[cfg(not(unix))]
+fn held_links(_project_paths: &[PathBuf]) -> HeldLinks {
+    HeldLinks::new()
+}
+
+#[cfg(unix)]
+fn link_count(path: &Path, held: &HeldLinks) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    match fs::metadata(path) {
+        Ok(meta) => {
+            let released = held.get(&(meta.dev(), meta.ino())).copied().unwrap_or(0);
+            meta.nlink().saturating_sub(released)
+        }
+        Err(_) => 1,
+    }
+}
+
+#[cfg(not(unix))]
+fn link_count(_path: &Path, _held: &HeldLinks) -> u64 {
+    1
+}
+
+// v3 names indexes files/xx/<hash>-index.json; v10 stores them as
+// index/xx/<hash>-<name>@<version>.json.
+fn is_index_file(root: &Path, path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    if path.starts_with(root.join("index")) {
+        name.ends_with(".json")
+    } else {
+        name.ends_with("-index.json")
+    }
+}
+
+// Recovers "<name>@<version>" from a v10 index name, where a scoped name's
+// slash is written as "+".
+fn index_file_id(root: &Path, index: &Path) -> Option<String> {
+    if !index.starts_with(root.join("index")) {
+        return None;
+    }
+    let stem = index.file_stem()?.to_string_lossy();
+    let (_, id) = stem.split_once('-')?;
+    id.get(1..)?.contains('@').then(|| id.replacen('+', "/", 1))
+}
+
+fn store_files(root: &Path) -> Vec<PathBuf> {
+    ["files", "index"]
+        .iter()
+        .flat_map(|dir| {
+            WalkDir::new(root.join(dir))
+                .follow_links(false)
+                .into_iter()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_file())
+                .map(|entry| entry.into_path())
+        })
+        .collect()
+}
+
+// Index entries name files by integrity; executables get an "-exec" suffix.
+fn pnpm_content_path(root: &Path, file: &serde_json::Value) -> Option<PathBuf> {
+    let integrity = file.get("integrity")?.as_str()?;
+    let digest = integrity.split_once('-')?.1;
+    let hex = hex::encode(STANDARD.decode(digest).ok()?);
+    if hex.len() < 3 {
+        return None;
+    }
+    let executable = file
+        .get("mode")
+        .and_then(|mode| mode.as_u64())
+        .map(|mode| mode & 0o111 != 0)
+        .unwrap_or(false);
+    let suffix = if executable { "-exec" } else { "" };
+    Some(
+        root.join("files")
+            .join(&hex[..2])
+            .join(format!("{}{suffix}", &hex[2..])),
+    )
+}
+
+fn read_pnpm_package(root: &Path, index: &Path) -> Option<PnpmPackage> {
+    let data: serde_json::Value = serde_json::from_str(&fs::read_to_string(index).ok()?).ok()?;
+    let name = data.get("name").and_then(|value| value.as_str());
+    let version = data.get("version").and_then(|value| value.as_str());
+    let id = match (name, version) {
+        (Some(name), Some(version)) => Some(format!("{name}@{version}")),
+        _ => index_file_id(root, index),
+    };
+
+    let mut file_maps: Vec<&serde_json::Map<String, serde_json::Value>> = Vec::new();
+    file_maps.extend(data.get("files").and_then(|files| files.as_object()));
+    if let Some(side_effects) = data.get("sideEffects").and_then(|value| value.as_object()) {
+        file_maps.extend(side_effects.values().filter_map(|files| files.as_object()));
+    }
+    let contents = file_maps
+        .iter()
+        .flat_map(|files| files.values())
+        .filter_map(|file| pnpm_content_path(root, file))
+        .collect();
+
+    Some(PnpmPackage {
+        index: index.to_path_buf(),
+        id,
+        contents,
+    })
+}
+
+// node_modules/.pnpm holds one folder per package, e.g. "@babel+core@7.23.0"
+// or "react-dom@18.2.0_react@18.2.0" with a peer suffix.
+fn virtual_store_id(folder: &str) -> Option<String> {
+    let split = folder.get(1..)?.find('@')? + 1;
+    let name = folder[..split].replacen('+', "/", 1);
+    let version = folder[split + 1..]
+        .split(['_', '('])
+        .next()
+        .filter(|version| !version.is_empty())?;
+    Some(format!("{name}@{version}"))
+}
+
+fn referenced_packages(project_paths: &[PathBuf]) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    for project in project_paths {
+        let entries = match fs::read_dir(project.join("node_modules").join(".pnpm")) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        ids.extend(
+            entries
+                .flatten()
+                .filter_map(|entry| virtual_store_id(&entry.file_name().to_string_lossy())),
+        );
+    }
+    ids
+}
+
+// A package survives when a remaining project lists it, its id can't be read,
+// or any of its files is still hard-linked from somewhere that is not being
+// deleted; everything else matches what `pnpm store prune` would drop.
+fn plan_pnpm(root: &Path, options: &PruneOptions) -> PnpmPrune {
+    let files = store_files(root);
+    let referenced = referenced_packages(&options.project_paths);
+    let held = held_links(&options.excluded_paths);
+    let packages: Vec<PnpmPackage> = files
+        .iter()
+        .filter(|path| is_index_file(root, path))
+        .filter_map(|index| read_pnpm_package(root, index))
+        .collect();
+
+    let mut kept: HashSet<&PathBuf> = HashSet::new();
+    let mut stale_indexes = Vec::new();
+    for package in &packages {
+        let in_use = package
+            .id
+            .as_ref()
+            .map(|id| referenced.contains(id))
+            .unwrap_or(true)
+            || package.contents.iter().any(|path| link_count(path, &held) > 1);
+        if in_use {
+            kept.extend(package.contents.iter());
+        } else {
+            stale_indexes.push(package.index.clone());
+        }
+    }
+
+    let mut stale: Vec<PathBuf> = files
+        .iter()
+        .filter(|path| {
+            !is_index_file(root, path) && !kept.contains(path) && link_count(path, &held) <= 1
+        })
+        .cloned()
+        .collect();
+    let pruned_entries = stale_indexes.len();
+    stale.extend(stale_indexes);
+    let reclaimed_bytes = stale
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum();
+
+    PnpmPrune {
+        summary: PruneSummary {
+            entry_count: packages.len(),
+            pruned_entries,
+            reclaimed_bytes,
+        },
+        files: stale,
+    }
+}
+
+fn remove_files(files: &[PathBuf]) -> u64 {
+    let mut reclaimed_bytes = 0u64;
+    for path in files {
+        let size = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+        if remove_path(path).is_ok() {
+            reclaimed_bytes += size;
+        }
+    }
+    reclaimed_bytes
+}
+
+/// Reports what pruning `path` would reclaim, or `None` when the folder is
+/// not a cache that supports partial pruning or is a pnpm store with no
+/// scanned projects to check it against.
+pub fn plan_prune(path: &Path, options: &PruneOptions) -> Option<PruneSummary> {
+    if let Some(root) = cacache_root(path) {
+        let cache = read_npm_cache(&root);
+        return Some(plan_npm(&cache, options).summary);
+    }
+    // Without scanned projects every package would look unused.
+    if options.project_paths.is_empty() {
+        return None;
+    }
+    let root = pnpm_store_root(path)?;
+    Some(plan_pnpm(&root, options).summary)
+}
+
+pub fn execute_prune(path: &Path, options: &PruneOptions) -> io::Result<PruneSummary> {
+    if let Some(root) = cacache_root(path) {
+        let cache = read_npm_cache(&root);
+        let prune = plan_npm(&cache, options);
+        rewrite_buckets(&cache, &prune.stale_keys)?;
+        let mut summary = prune.summary;
+        summary.reclaimed_bytes = remove_files(&prune.stale_content);
+        return Ok(summary);
+    }
+    if let Some(root) = pnpm_store_root(path) {
+        if options.project_paths.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Pruning a pnpm store needs the scanned projects",
+            ));
+        }
+        let prune = plan_pnpm(&root, options);
+        let mut summary = prune.summary;
+        summary.reclaimed_bytes = remove_files(&prune.files);
+        return Ok(summary);
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "Folder does not support pruning",
+    ))
+}
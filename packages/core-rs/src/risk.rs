@@ -1,16 +1,21 @@
-use crate::types::{ProjectMeta, RiskAssessment, RiskClass, RiskSource};
+use crate::types::{CacheKind, ProjectMeta, RiskAssessment, RiskClass, RiskSource};
+use std::path::Path;
+use walkdir::WalkDir;
 
 const STARTUP_HINTS: &[&str] = &["startup", "production", "prod"];
 const BURNER_HINTS: &[&str] = &["tutorial", "test", "boilerplate", "example", "sample"];
+const PROTECTED_SUFFIXES: &[&str] = &[".tfstate", ".tfstate.backup"];
+const CRITICAL_SCORE: u8 = 8;
+const ACTIVE_SCORE: u8 = 5;
 
 fn clamp_score(score: i32) -> u8 {
     score.clamp(0, 10) as u8
 }
 
 fn classify(score: u8) -> RiskClass {
-    if score >= 8 {
+    if score >= CRITICAL_SCORE {
         RiskClass::Critical
-    } else if score >= 5 {
+    } else if score >= ACTIVE_SCORE {
         RiskClass::Active
     } else {
         RiskClass::Burner
@@ -26,6 +31,34 @@ pub fn evaluate_heuristic(project: &ProjectMeta) -> RiskAssessment {
         reasons.push("System cache directory".to_string());
     }
 
+    match project.cache_kind {
+        Some(CacheKind::Derived) => {
+            score -= 2;
+            reasons.push("Rebuilt locally from other cached data".to_string());
+        }
+        Some(CacheKind::Download) => {
+            score += 6;
+            reasons.push("Must be downloaded again after deletion".to_string());
+        }
+        Some(CacheKind::Toolchain) => {
+            score += 6;
+            reasons.push("Installed toolchain".to_string());
+        }
+        None => {}
+    }
+
+    match project.in_use {
+        Some(true) => {
+            score += 3;
+            reasons.push("Pinned by a scanned project".to_string());
+        }
+        Some(false) => {
+            score -= 3;
+            reasons.push("Not pinned by any scanned project".to_string());
+        }
+        None => {}
+    }
+
     if project.has_git {
         score += 4;
         reasons.push("Git history detected".to_string());
@@ -36,9 +69,14 @@ pub fn evaluate_heuristic(project: &ProjectMeta) -> RiskAssessment {
         reasons.push("Environment file present".to_string());
     }
 
+    if project.has_state_file {
+        score += 4;
+        reasons.push("Infrastructure state file present".to_string());
+    }
+
     if project.has_startup_keyword {
         score += 3;
-        reasons.push("Startup keywords in package.json".to_string());
+        reasons.push("Startup keywords in project manifest".to_string());
     }
 
     if project.last_modified_days <= 30 {
@@ -61,7 +99,12 @@ pub fn evaluate_heuristic(project: &ProjectMeta) -> RiskAssessment {
         reasons.push("Inactive for 6+ months".to_string());
     }
 
-    let score = clamp_score(score);
+    let mut score = clamp_score(score);
+    // Whatever else counts against it, a cache a project still pins is never
+    // a burner.
+    if project.in_use == Some(true) {
+        score = score.max(ACTIVE_SCORE);
+    }
 
     RiskAssessment {
         class_name: classify(score),
@@ -107,3 +150,18 @@ pub fn is_burner_name(name: &str) -> bool {
     let lowered = name.to_lowercase();
     BURNER_HINTS.iter().any(|hint| lowered.contains(hint))
 }
+
+pub fn is_protected_file(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    PROTECTED_SUFFIXES
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+pub fn contains_protected_files(path: &Path) -> bool {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| entry.file_type().is_file() && is_protected_file(entry.path()))
+}
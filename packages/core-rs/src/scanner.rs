@@ -1,23 +1,30 @@
-use crate::risk::has_startup_signal;
-use crate::types::{ProjectMeta, ScanProgress};
+use crate::caches::{tool_candidates, with_folder_label, CacheCandidate};
+use crate::detect::{detectors, is_project_artifact, ProjectDetector};
+use crate::risk::{has_startup_signal, is_protected_file};
+use crate::types::{ProjectArtifact, ProjectMeta, ScanProgress};
+use crate::walk::{list_dir, tree_size, with_threads, TreeSize};
 use dirs::{cache_dir, data_dir, home_dir};
-use std::collections::{HashMap, HashSet};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
-use walkdir::{DirEntry, WalkDir};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const DEFAULT_IGNORES: &[&str] = &[
     "node_modules",
     "dist",
-    "build",
     ".git",
     ".next",
     ".cache",
     "coverage",
 ];
 
+#[cfg(not(target_os = "windows"))]
 const FULL_DISK_IGNORES_UNIX: &[&str] = &[
     "System",
     "Library",
@@ -31,6 +38,7 @@ const FULL_DISK_IGNORES_UNIX: &[&str] = &[
     "tmp",
 ];
 
+#[cfg(target_os = "windows")]
 const FULL_DISK_IGNORES_WINDOWS: &[&str] = &[
     "Windows",
     "Program Files",
@@ -40,12 +48,16 @@ const FULL_DISK_IGNORES_WINDOWS: &[&str] = &[
     "System Volume Information",
 ];
 
-fn is_ignored(entry: &DirEntry, scan_all: bool) -> bool {
-    let name = entry.file_name().to_string_lossy();
+pub(crate) fn is_ignored(dir: &Path, depth: usize, scan_all: bool) -> bool {
+    let name = dir.file_name().unwrap_or_default().to_string_lossy();
     if DEFAULT_IGNORES.iter().any(|item| *item == name) {
         return true;
     }
 
+    if depth > 0 && is_project_artifact(dir) {
+        return true;
+    }
+
     if !scan_all {
         return false;
     }
@@ -58,23 +70,48 @@ fn is_ignored(entry: &DirEntry, scan_all: bool) -> bool {
     full_disk_list.iter().any(|item| *item == name)
 }
 
-fn read_package_json(path: &Path) -> Option<serde_json::Value> {
-    let data = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&data).ok()
-}
+fn fold_workspace_members(
+    manifest_paths: &mut BTreeMap<PathBuf, Vec<(usize, PathBuf)>>,
+    registry: &[&dyn ProjectDetector],
+) {
+    let mut folds: Vec<(PathBuf, usize, PathBuf)> = Vec::new();
+    for (project_dir, manifests) in manifest_paths.iter() {
+        for (index, detector) in registry.iter().enumerate() {
+            let paths: Vec<PathBuf> = manifests
+                .iter()
+                .filter(|(item, _)| *item == index)
+                .map(|(_, path)| path.clone())
+                .collect();
+            if paths.is_empty() {
+                continue;
+            }
+            for member in detector.workspace_members(project_dir, &paths) {
+                let is_descendant = member != *project_dir
+                    && member.starts_with(project_dir)
+                    && member
+                        .components()
+                        .all(|part| !matches!(part, Component::ParentDir | Component::CurDir));
+                if is_descendant && manifest_paths.contains_key(&member) {
+                    folds.push((project_dir.clone(), index, member));
+                }
+            }
+        }
+    }
 
-fn get_dependency_count(pkg: &serde_json::Value) -> usize {
-    let keys = [
-        "dependencies",
-        "devDependencies",
-        "peerDependencies",
-        "optionalDependencies",
-    ];
-    keys.iter()
-        .filter_map(|key| pkg.get(key))
-        .filter_map(|value| value.as_object())
-        .map(|map| map.len())
-        .sum()
+    for (project_dir, index, member) in folds.into_iter().rev() {
+        let moved: Vec<(usize, PathBuf)> = match manifest_paths.get_mut(&member) {
+            Some(manifests) => {
+                let (moved, kept) = manifests.drain(..).partition(|(item, _)| *item == index);
+                *manifests = kept;
+                moved
+            }
+            None => continue,
+        };
+        if manifest_paths.get(&member).is_some_and(|manifests| manifests.is_empty()) {
+            manifest_paths.remove(&member);
+        }
+        manifest_paths.entry(project_dir).or_default().extend(moved);
+    }
 }
 
 fn has_env_file(dir: &Path) -> bool {
@@ -86,6 +123,15 @@ fn has_env_file(dir: &Path) -> bool {
     false
 }
 
+fn has_state_file(dir: &Path) -> bool {
+    if let Ok(entries) = fs::read_dir(dir) {
+        return entries
+            .flatten()
+            .any(|entry| is_protected_file(&entry.path()));
+    }
+    false
+}
+
 fn last_modified_ms(path: &Path) -> Option<i64> {
     let metadata = fs::metadata(path).ok()?;
     let modified = metadata.modified().ok()?;
@@ -102,17 +148,6 @@ fn last_modified_days(ms: i64) -> i64 {
     delta_ms / (1000 * 60 * 60 * 24)
 }
 
-fn directory_size_bytes(dir: &Path) -> u64 {
-    WalkDir::new(dir)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().is_file())
-        .filter_map(|entry| entry.metadata().ok())
-        .map(|meta| meta.len())
-        .sum()
-}
-
 fn path_id(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
@@ -121,13 +156,7 @@ fn dir_exists(path: &Path) -> bool {
     fs::metadata(path).map(|meta| meta.is_dir()).unwrap_or(false)
 }
 
-struct CacheCandidate {
-    path: PathBuf,
-    label: String,
-    expand_children: bool,
-}
-
-fn gather_cache_candidates() -> Vec<CacheCandidate> {
+fn gather_cache_candidates(project_dirs: &[PathBuf]) -> Vec<CacheCandidate> {
     let mut candidates: Vec<CacheCandidate> = Vec::new();
 
     if let Some(path) = cache_dir() {
@@ -135,6 +164,8 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path,
             label: "System cache".to_string(),
             expand_children: true,
+            kind: None,
+            in_use: None,
         });
     }
 
@@ -143,31 +174,43 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path: path.join(".npm"),
             label: "npm cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
         candidates.push(CacheCandidate {
             path: path.join(".yarn").join("cache"),
             label: "yarn cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
         candidates.push(CacheCandidate {
             path: path.join(".yarn"),
             label: "yarn data".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
         candidates.push(CacheCandidate {
             path: path.join(".pnpm-store"),
             label: "pnpm store".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
         candidates.push(CacheCandidate {
             path: path.join(".cache").join("yarn"),
             label: "yarn cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
         candidates.push(CacheCandidate {
             path: path.join(".cache").join("npm"),
             label: "npm cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
     }
 
@@ -176,6 +219,8 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path: path.join("pnpm").join("store"),
             label: "pnpm store".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
     }
 
@@ -184,6 +229,8 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path: PathBuf::from(value),
             label: "npm cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
     }
     if let Some(value) = env::var_os("YARN_CACHE_FOLDER") {
@@ -191,6 +238,8 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path: PathBuf::from(value),
             label: "yarn cache".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
     }
     if let Some(value) = env::var_os("PNPM_STORE_PATH") {
@@ -198,18 +247,36 @@ fn gather_cache_candidates() -> Vec<CacheCandidate> {
             path: PathBuf::from(value),
             label: "pnpm store".to_string(),
             expand_children: false,
+            kind: None,
+            in_use: None,
         });
     }
 
+    let mut candidates: Vec<CacheCandidate> = candidates
+        .into_iter()
+        .map(|candidate| {
+            if candidate.expand_children {
+                candidate
+            } else {
+                with_folder_label(candidate)
+            }
+        })
+        .collect();
+    candidates.extend(tool_candidates(project_dirs));
     candidates
 }
 
-fn scan_cache_dirs() -> Vec<ProjectMeta> {
+fn scan_cache_dirs(project_dirs: &[PathBuf]) -> Vec<ProjectMeta> {
     let mut projects = Vec::new();
     let mut seen = HashSet::new();
     let mut labels: HashMap<String, String> = HashMap::new();
 
-    for candidate in gather_cache_candidates() {
+    // Specific candidates claim their paths before the generic cache folders
+    // are expanded, so ~/.cache/pip keeps its own label.
+    let mut candidates = gather_cache_candidates(project_dirs);
+    candidates.sort_by_key(|candidate| candidate.expand_children);
+
+    for candidate in candidates {
         if !dir_exists(&candidate.path) {
             continue;
         }
@@ -239,21 +306,27 @@ fn scan_cache_dirs() -> Vec<ProjectMeta> {
                 labels.insert(path_key, format!("{} - {}", candidate.label, folder_name));
                 let last_modified = last_modified_ms(&entry_path).unwrap_or(0);
                 let modified_days = last_modified_days(last_modified);
-                let size_bytes = directory_size_bytes(&entry_path);
+                let size_bytes = tree_size(&entry_path).bytes;
 
                 projects.push(ProjectMeta {
                     id: path_id(&entry_path),
                     path: entry_path.to_string_lossy().to_string(),
                     name: folder_name,
-                    package_json_path: String::new(),
+                    ecosystem: None,
+                    manifests: Vec::new(),
+                    artifacts: Vec::new(),
                     dependency_count: 0,
                     has_git: false,
                     has_env_file: false,
+                    has_state_file: false,
                     has_startup_keyword: false,
                     last_modified,
                     last_modified_days: modified_days,
                     size_bytes,
                     is_cache: true,
+                    cache_kind: candidate.kind,
+                    in_use: candidate.in_use,
+                    parent_id: None,
                 });
             }
             continue;
@@ -270,24 +343,30 @@ fn scan_cache_dirs() -> Vec<ProjectMeta> {
             .unwrap_or_default()
             .to_string_lossy()
             .to_string();
-        labels.insert(path_key, format!("{} - {}", candidate.label, name));
+        labels.insert(path_key, candidate.label.clone());
         let last_modified = last_modified_ms(&candidate.path).unwrap_or(0);
         let modified_days = last_modified_days(last_modified);
-        let size_bytes = directory_size_bytes(&candidate.path);
+        let size_bytes = tree_size(&candidate.path).bytes;
 
         projects.push(ProjectMeta {
             id: path_id(&candidate.path),
             path: candidate.path.to_string_lossy().to_string(),
             name,
-            package_json_path: String::new(),
+            ecosystem: None,
+            manifests: Vec::new(),
+            artifacts: Vec::new(),
             dependency_count: 0,
             has_git: false,
             has_env_file: false,
+            has_state_file: false,
             has_startup_keyword: false,
             last_modified,
             last_modified_days: modified_days,
             size_bytes,
             is_cache: true,
+            cache_kind: candidate.kind,
+            in_use: candidate.in_use,
+            parent_id: None,
         });
     }
 
@@ -297,157 +376,500 @@ fn scan_cache_dirs() -> Vec<ProjectMeta> {
         }
     }
 
+    link_cache_parents(&mut projects);
     projects
 }
 
+// Candidates overlap (~/.yarn holds ~/.yarn/cache, the expanded system cache
+// holds most tool caches), so each entry points at its closest enclosing
+// entry. Sorting by path keeps every subtree contiguous behind its root.
+fn link_cache_parents(projects: &mut [ProjectMeta]) {
+    projects.sort_by(|a, b| Path::new(&a.path).cmp(Path::new(&b.path)));
+    let mut ancestors: Vec<(PathBuf, String)> = Vec::new();
+    for project in projects.iter_mut() {
+        let path = PathBuf::from(&project.path);
+        while ancestors
+            .last()
+            .is_some_and(|(ancestor, _)| !path.starts_with(ancestor))
+        {
+            ancestors.pop();
+        }
+        project.parent_id = ancestors.last().map(|(_, id)| id.clone());
+        ancestors.push((path, project.id.clone()));
+    }
+}
+
+#[derive(Default)]
+struct WalkProgress {
+    scanned: AtomicUsize,
+    listed: AtomicUsize,
+    current_path: Mutex<PathBuf>,
+    projects: Mutex<HashSet<PathBuf>>,
+}
+
+impl WalkProgress {
+    fn found_count(&self) -> usize {
+        self.projects
+            .lock()
+            .map(|projects| projects.len())
+            .unwrap_or(0)
+    }
+
+    fn snapshot(&self) -> ScanProgress {
+        let current_path = self
+            .current_path
+            .lock()
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default();
+        ScanProgress {
+            found_count: self.found_count(),
+            current_path,
+            scanned_count: self.scanned.load(Ordering::Relaxed),
+            total_count: Some(self.listed.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+type DirSizes = Mutex<HashMap<PathBuf, u64>>;
+
+#[derive(Default)]
+struct DirScan {
+    manifests: Vec<(usize, PathBuf, PathBuf)>,
+    // Project folders found below that enclose the scanned folder itself, as
+    // when a Unity project is recognised by ProjectSettings/.
+    outer_projects: Vec<PathBuf>,
+    nested_artifacts: Vec<(usize, PathBuf)>,
+    protected_files: Vec<PathBuf>,
+    pruned_dirs: Vec<PathBuf>,
+    total_entries: usize,
+    skipped_entries: usize,
+}
+
+impl DirScan {
+    fn merge(&mut self, other: DirScan) {
+        self.manifests.extend(other.manifests);
+        self.outer_projects.extend(other.outer_projects);
+        self.nested_artifacts.extend(other.nested_artifacts);
+        self.protected_files.extend(other.protected_files);
+        self.pruned_dirs.extend(other.pruned_dirs);
+        self.total_entries += other.total_entries;
+        self.skipped_entries += other.skipped_entries;
+    }
+}
+
+enum Visit {
+    Scan(PathBuf),
+    Size(PathBuf),
+}
+
+// Files are handled before subdirectories, so manifests beside an ignored
+// folder are known before deciding whether to size it. Ignored folders inside
+// a project (node_modules, target, .git) are still walked, only for their
+// size; the rest of the ignore list is skipped outright. Subdirectories run in
+// parallel and their results are merged in name order, so the outcome does
+// not depend on the thread count. Sizes are kept in `sizes` only for folders
+// at or below a project root, the only ones looked up afterwards. Inside a
+// project, folders detectors report as nested artifacts (__pycache__, CMake
+// build trees) are collected and sized rather than walked. Returns the scan
+// and the folder's size.
+fn scan_dir(
+    dir: &Path,
+    project: Option<&Path>,
+    scan_all: bool,
+    progress: &WalkProgress,
+    sizes: &DirSizes,
+) -> (DirScan, u64) {
+    let mut scan = DirScan::default();
+    let listing = match list_dir(dir) {
+        Some(listing) => listing,
+        None => {
+            scan.skipped_entries += 1;
+            if project.is_some() {
+                record_size(sizes, dir, 0);
+            }
+            return (scan, 0);
+        }
+    };
+    scan.skipped_entries += listing.errors;
+    progress
+        .listed
+        .fetch_add(listing.files.len() + listing.dirs.len(), Ordering::Relaxed);
+    if let Ok(mut current_path) = progress.current_path.try_lock() {
+        *current_path = dir.to_path_buf();
+    }
+
+    let mut found_project: Option<PathBuf> = None;
+    let mut bytes = 0u64;
+    for (path, size) in &listing.files {
+        scan.total_entries += 1;
+        let size = match size {
+            Some(size) => *size,
+            None => continue,
+        };
+        bytes += size;
+        if is_protected_file(path) {
+            scan.protected_files.push(path.clone());
+        }
+
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        for (index, detector) in detectors().iter().enumerate() {
+            if !detector.is_manifest(&file_name) {
+                continue;
+            }
+            let project_dir = match detector.project_dir(path) {
+                Some(project_dir) => project_dir,
+                None => continue,
+            };
+            let is_nearer = found_project
+                .as_ref()
+                .is_none_or(|found| project_dir.starts_with(found));
+            if dir.starts_with(&project_dir) && is_nearer {
+                found_project = Some(project_dir.clone());
+            }
+            if let Ok(mut projects) = progress.projects.lock() {
+                projects.insert(project_dir.clone());
+            }
+            if project_dir != dir {
+                scan.outer_projects.push(project_dir.clone());
+            }
+            scan.manifests.push((index, project_dir, path.clone()));
+        }
+    }
+
+    let project = found_project.as_deref().or(project);
+    let mut in_project = project.is_some();
+    let mut visits = Vec::new();
+    for path in listing.dirs {
+        scan.total_entries += 1;
+        let nested = project.and_then(|project| {
+            detectors()
+                .iter()
+                .position(|detector| detector.is_nested_artifact(project, &path))
+        });
+        if let Some(index) = nested {
+            scan.nested_artifacts.push((index, path.clone()));
+            visits.push(Visit::Size(path));
+        } else if !is_ignored(&path, 1, scan_all) {
+            visits.push(Visit::Scan(path));
+        } else if in_project || is_project_artifact(&path) {
+            visits.push(Visit::Size(path));
+        } else {
+            scan.pruned_dirs.push(path);
+        }
+    }
+    progress
+        .scanned
+        .fetch_add(scan.total_entries, Ordering::Relaxed);
+
+    let children: Vec<(DirScan, u64)> = visits
+        .into_par_iter()
+        .map(|visit| match visit {
+            Visit::Scan(path) => scan_dir(&path, project, scan_all, progress, sizes),
+            Visit::Size(path) => {
+                let size = tree_size(&path);
+                record_size(sizes, &path, size.bytes);
+                let child = DirScan {
+                    protected_files: size.protected_files,
+                    ..DirScan::default()
+                };
+                (child, size.bytes)
+            }
+        })
+        .collect();
+    for (child, child_bytes) in children {
+        bytes += child_bytes;
+        scan.merge(child);
+    }
+
+    in_project |= scan.outer_projects.iter().any(|project| project == dir);
+    scan.outer_projects.retain(|project| project != dir);
+    if in_project {
+        record_size(sizes, dir, bytes);
+    }
+    (scan, bytes)
+}
+
+fn record_size(sizes: &DirSizes, dir: &Path, bytes: u64) {
+    if let Ok(mut sizes) = sizes.lock() {
+        sizes.insert(dir.to_path_buf(), bytes);
+    }
+}
+
+struct TreeWalk {
+    manifest_paths: BTreeMap<PathBuf, Vec<(usize, PathBuf)>>,
+    nested_artifacts: Vec<(usize, PathBuf)>,
+    dir_sizes: HashMap<PathBuf, u64>,
+    protected_files: Vec<PathBuf>,
+    total_entries: usize,
+    skipped_entries: usize,
+}
+
+impl TreeWalk {
+    fn new(root: &Path, scan_all: bool, progress: &WalkProgress) -> Self {
+        progress.scanned.fetch_add(1, Ordering::Relaxed);
+        progress.listed.fetch_add(1, Ordering::Relaxed);
+        let mut scan = DirScan {
+            total_entries: 1,
+            ..DirScan::default()
+        };
+        let sizes = DirSizes::default();
+        if is_ignored(root, 0, scan_all) {
+            record_size(&sizes, root, 0);
+        } else {
+            scan.merge(scan_dir(root, None, scan_all, progress, &sizes).0);
+        }
+
+        let mut manifest_paths: BTreeMap<PathBuf, Vec<(usize, PathBuf)>> = BTreeMap::new();
+        for (index, project_dir, path) in scan.manifests {
+            manifest_paths
+                .entry(project_dir)
+                .or_default()
+                .push((index, path));
+        }
+        let mut walk = Self {
+            manifest_paths,
+            nested_artifacts: scan.nested_artifacts,
+            dir_sizes: sizes.into_inner().unwrap_or_default(),
+            protected_files: scan.protected_files,
+            total_entries: scan.total_entries,
+            skipped_entries: scan.skipped_entries,
+        };
+
+        // A folder skipped before its project's manifest turned up, such as
+        // .git beside a Unity project's ProjectSettings, is sized now so
+        // project totals stay whole.
+        let late: Vec<(PathBuf, TreeSize)> = scan
+            .pruned_dirs
+            .into_par_iter()
+            .filter(|path| walk.within_project(path))
+            .map(|path| {
+                let size = tree_size(&path);
+                (path, size)
+            })
+            .collect();
+        for (path, size) in late {
+            for ancestor in path.ancestors().skip(1) {
+                if let Some(total) = walk.dir_sizes.get_mut(ancestor) {
+                    *total += size.bytes;
+                }
+            }
+            walk.dir_sizes.insert(path, size.bytes);
+            walk.protected_files.extend(size.protected_files);
+        }
+        walk
+    }
+
+    fn within_project(&self, dir: &Path) -> bool {
+        dir.ancestors()
+            .any(|ancestor| self.manifest_paths.contains_key(ancestor))
+    }
+
+    fn size_of(&self, dir: &Path) -> u64 {
+        self.dir_sizes
+            .get(dir)
+            .copied()
+            .unwrap_or_else(|| tree_size(dir).bytes)
+    }
+
+    // Only files seen by the walk count. Folders outside it, such as Bazel's
+    // output base, hold no state files of the project and are not walked a
+    // second time to find out.
+    fn has_protected_files(&self, dir: &Path) -> bool {
+        self.protected_files
+            .iter()
+            .any(|file| file.starts_with(dir))
+    }
+}
+
 pub struct ScanResult {
     pub projects: Vec<ProjectMeta>,
     pub total_entries: usize,
     pub skipped_entries: usize,
 }
 
+/// Scans `root` on `threads` workers (zero picks one per core). Progress is
+/// reported from the calling thread, so `on_progress` needs no `Send` bound.
 pub fn scan_projects<F>(
     root: &Path,
     scan_all: bool,
     scan_caches: bool,
+    threads: usize,
     mut on_progress: Option<F>,
 ) -> ScanResult
 where
     F: FnMut(ScanProgress),
 {
-    let mut total_entries = 0usize;
-    let mut skipped_entries = 0usize;
-    let count_walker = WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|entry| !is_ignored(entry, scan_all));
-
-    for entry in count_walker {
-        match entry {
-            Ok(_) => total_entries += 1,
-            Err(_) => skipped_entries += 1,
-        }
-    }
-
-    let mut package_paths: Vec<PathBuf> = Vec::new();
-    let mut found_count = 0usize;
-    let mut scanned_count = 0usize;
-    let mut last_emit = Instant::now();
+    let progress = WalkProgress::default();
+    thread::scope(|scope| {
+        let (done_tx, done_rx) = mpsc::channel();
+        let progress = &progress;
+        let worker = scope.spawn(move || {
+            let result = with_threads(threads, || {
+                collect_projects(root, scan_all, scan_caches, progress)
+            });
+            let _ = done_tx.send(());
+            result
+        });
 
-    let walker = WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|entry| !is_ignored(entry, scan_all));
-
-    for entry in walker.flatten() {
-        let mut found_update = false;
-        scanned_count += 1;
-        if entry.file_type().is_file() && entry.file_name() == "package.json" {
-            let path = entry.path().to_path_buf();
-            package_paths.push(path.clone());
-            found_count += 1;
-            found_update = true;
+        while let Err(RecvTimeoutError::Timeout) = done_rx.recv_timeout(Duration::from_millis(120))
+        {
+            if let Some(callback) = on_progress.as_mut() {
+                callback(progress.snapshot());
+            }
         }
+        let result = worker
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
 
         if let Some(callback) = on_progress.as_mut() {
-            if found_update || last_emit.elapsed().as_millis() >= 120 || scanned_count % 200 == 0 {
-                last_emit = Instant::now();
-                callback(ScanProgress {
-                    found_count,
-                    current_path: entry.path().to_string_lossy().to_string(),
-                    scanned_count,
-                    total_count: Some(total_entries),
-                });
-            }
+            callback(ScanProgress {
+                found_count: progress.found_count(),
+                current_path: root.to_string_lossy().to_string(),
+                scanned_count: result.total_entries,
+                total_count: Some(result.total_entries),
+            });
         }
-    }
+        result
+    })
+}
 
-    if let Some(callback) = on_progress.as_mut() {
-        callback(ScanProgress {
-            found_count,
-            current_path: root.to_string_lossy().to_string(),
-            scanned_count,
-            total_count: Some(total_entries),
-        });
+fn collect_projects(
+    root: &Path,
+    scan_all: bool,
+    scan_caches: bool,
+    progress: &WalkProgress,
+) -> ScanResult {
+    let registry = detectors();
+    let mut walk = TreeWalk::new(root, scan_all, progress);
+    let mut manifest_paths = std::mem::take(&mut walk.manifest_paths);
+    fold_workspace_members(&mut manifest_paths, registry);
+    let mut nested_artifacts: HashMap<&Path, Vec<(usize, PathBuf)>> = HashMap::new();
+    for (index, path) in std::mem::take(&mut walk.nested_artifacts) {
+        let owner = path
+            .ancestors()
+            .skip(1)
+            .find_map(|ancestor| manifest_paths.get_key_value(ancestor));
+        if let Some((project_dir, _)) = owner {
+            nested_artifacts
+                .entry(project_dir.as_path())
+                .or_default()
+                .push((index, path));
+        }
     }
-
     let mut projects = Vec::new();
 
-    for package_json_path in package_paths {
-        let project_dir = package_json_path.parent().unwrap_or(root);
-        let pkg = match read_package_json(&package_json_path) {
-            Some(value) => value,
-            None => continue,
-        };
+    for (project_dir, manifests) in &manifest_paths {
+        let project_dir = project_dir.clone();
+        let mut by_detector: BTreeMap<usize, Vec<PathBuf>> = BTreeMap::new();
+        for (index, path) in manifests {
+            by_detector.entry(*index).or_default().push(path.clone());
+        }
 
-        let name = pkg
-            .get("name")
-            .and_then(|value| value.as_str())
-            .filter(|value| !value.trim().is_empty())
-            .map(|value| value.to_string())
-            .unwrap_or_else(|| {
-                project_dir
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string()
-            });
+        let mut ecosystem = None;
+        let mut primary_name = None;
+        let mut primary_manifest = None;
+        let mut manifest_list: Vec<String> = Vec::new();
+        let mut artifact_dirs: Vec<PathBuf> = Vec::new();
+        let mut dependency_count = 0usize;
+        let mut keywords: Vec<String> = Vec::new();
+        let mut scripts: Vec<String> = Vec::new();
+
+        for (index, paths) in &mut by_detector {
+            paths.sort();
+            let detector = registry[*index];
+            let details = match detector.read_manifests(&project_dir, paths) {
+                Some(details) => details,
+                None => continue,
+            };
 
-        let keywords = pkg
-            .get("keywords")
-            .and_then(|value| value.as_array())
-            .map(|items| {
-                items
-                    .iter()
-                    .filter_map(|item| item.as_str().map(|value| value.to_string()))
-                    .collect::<Vec<String>>()
-            })
-            .unwrap_or_default();
+            if ecosystem.is_none() {
+                ecosystem = Some(detector.ecosystem());
+                primary_name = details.name.clone();
+                primary_manifest = paths.first().cloned();
+            }
+            manifest_list.extend(paths.iter().map(|path| path.to_string_lossy().to_string()));
+            let nested = nested_artifacts
+                .get(project_dir.as_path())
+                .into_iter()
+                .flatten()
+                .filter(|(owner, _)| owner == index)
+                .map(|(_, path)| path.clone());
+            for artifact in detector.artifact_dirs(&project_dir).into_iter().chain(nested) {
+                if !artifact_dirs.contains(&artifact) && !walk.has_protected_files(&artifact) {
+                    artifact_dirs.push(artifact);
+                }
+            }
+            dependency_count += details.dependency_count;
+            keywords.extend(details.keywords);
+            scripts.extend(details.scripts);
+        }
 
-        let scripts = pkg
-            .get("scripts")
-            .and_then(|value| value.as_object())
-            .map(|map| {
-                map.values()
-                    .filter_map(|value| value.as_str().map(|value| value.to_string()))
-                    .collect::<Vec<String>>()
-            })
-            .unwrap_or_default();
+        if ecosystem.is_none() {
+            continue;
+        }
+
+        let name = primary_name.unwrap_or_else(|| {
+            project_dir
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        });
 
-        let dependency_count = get_dependency_count(&pkg);
         let has_git = project_dir.join(".git").exists();
-        let has_env = has_env_file(project_dir);
+        let has_env = has_env_file(&project_dir);
+        let has_state = has_state_file(&project_dir);
         let has_startup = has_startup_signal(&name, &keywords, &scripts);
-        let last_modified = last_modified_ms(&package_json_path)
-            .or_else(|| last_modified_ms(project_dir))
+        let last_modified = primary_manifest
+            .as_deref()
+            .and_then(last_modified_ms)
+            .or_else(|| last_modified_ms(&project_dir))
             .unwrap_or(0);
         let modified_days = last_modified_days(last_modified);
-        let size_bytes = directory_size_bytes(project_dir);
+        let size_bytes = walk.size_of(&project_dir);
+        let artifacts = artifact_dirs
+            .iter()
+            .map(|path| ProjectArtifact {
+                path: path.to_string_lossy().to_string(),
+                size_bytes: walk.size_of(path),
+                outside_project: !path.starts_with(&project_dir),
+            })
+            .collect();
 
         projects.push(ProjectMeta {
-            id: path_id(project_dir),
+            id: path_id(&project_dir),
             path: project_dir.to_string_lossy().to_string(),
             name,
-            package_json_path: package_json_path.to_string_lossy().to_string(),
+            ecosystem,
+            manifests: manifest_list,
+            artifacts,
             dependency_count,
             has_git,
             has_env_file: has_env,
+            has_state_file: has_state,
             has_startup_keyword: has_startup,
             last_modified,
             last_modified_days: modified_days,
             size_bytes,
             is_cache: false,
+            cache_kind: None,
+            in_use: None,
+            parent_id: None,
         });
     }
 
     if scan_caches {
-        projects.extend(scan_cache_dirs());
+        let project_dirs: Vec<PathBuf> = projects
+            .iter()
+            .map(|project| PathBuf::from(&project.path))
+            .collect();
+        projects.extend(scan_cache_dirs(&project_dirs));
     }
 
     projects.sort_by(|a, b| a.path.cmp(&b.path));
     ScanResult {
         projects,
-        total_entries,
-        skipped_entries,
+        total_entries: walk.total_entries,
+        skipped_entries: walk.skipped_entries,
     }
 }
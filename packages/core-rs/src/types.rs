@@ -14,6 +14,33 @@ pub enum RiskSource {
     Combined,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Ecosystem {
+    Node,
+    Rust,
+    Python,
+    Jvm,
+    Go,
+    Dotnet,
+    Swift,
+    Dart,
+    Php,
+    Ruby,
+    Elixir,
+    Haskell,
+    Infra,
+    Cpp,
+    Unity,
+    Unreal,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CacheKind {
+    Derived,
+    Download,
+    Toolchain,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskAssessment {
@@ -23,21 +50,37 @@ pub struct RiskAssessment {
     pub source: RiskSource,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectArtifact {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Lives outside the project folder, such as a Bazel output base.
+    pub outside_project: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMeta {
     pub id: String,
     pub path: String,
     pub name: String,
-    pub package_json_path: String,
+    pub ecosystem: Option<Ecosystem>,
+    pub manifests: Vec<String>,
+    pub artifacts: Vec<ProjectArtifact>,
     pub dependency_count: usize,
     pub has_git: bool,
     pub has_env_file: bool,
+    pub has_state_file: bool,
     pub has_startup_keyword: bool,
     pub last_modified: i64,
     pub last_modified_days: i64,
     pub size_bytes: u64,
     pub is_cache: bool,
+    pub cache_kind: Option<CacheKind>,
+    pub in_use: Option<bool>,
+    /// Id of the closest cache entry whose folder contains this one.
+    pub parent_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
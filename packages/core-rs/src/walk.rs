@@ -0,0 +1,109 @@
+use crate::risk::is_protected_file;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Walks recurse once per directory level, so workers get more stack than the
+// 2 MiB thread default to cope with deeply nested node_modules trees.
+const WORKER_STACK_BYTES: usize = 8 * 1024 * 1024;
+
+/// Runs `op` on a pool of `threads` workers. Zero picks one per core.
+pub(crate) fn with_threads<R, OP>(threads: usize, op: OP) -> R
+where
+    R: Send,
+    OP: FnOnce() -> R + Send,
+{
+    match ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .stack_size(WORKER_STACK_BYTES)
+        .build()
+    {
+        Ok(pool) => pool.install(op),
+        Err(_) => op(),
+    }
+}
+
+/// One directory read without following links. Files carry their length
+/// (`None` for symlinks and other special entries) and both lists are sorted
+/// by path so callers visit entries in the same order on every run.
+pub(crate) struct Listing {
+    pub files: Vec<(PathBuf, Option<u64>)>,
+    pub dirs: Vec<PathBuf>,
+    pub errors: usize,
+}
+
+pub(crate) fn list_dir(dir: &Path) -> Option<Listing> {
+    let mut listing = Listing {
+        files: Vec::new(),
+        dirs: Vec::new(),
+        errors: 0,
+    };
+    for entry in fs::read_dir(dir).ok()? {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => {
+                listing.errors += 1;
+                continue;
+            }
+        };
+        match entry.file_type() {
+            Ok(kind) if kind.is_dir() => listing.dirs.push(entry.path()),
+            Ok(kind) if kind.is_file() => {
+                let size = entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+                listing.files.push((entry.path(), Some(size)));
+            }
+            Ok(_) => listing.files.push((entry.path(), None)),
+            Err(_) => listing.errors += 1,
+        }
+    }
+    listing.files.sort();
+    listing.dirs.sort();
+    Some(listing)
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TreeSize {
+    pub bytes: u64,
+    pub protected_files: Vec<PathBuf>,
+}
+
+/// Sizes everything under `path`, spreading subdirectories over the current
+/// pool. A plain file reports its own length and a symlink counts as empty.
+pub(crate) fn tree_size(path: &Path) -> TreeSize {
+    if let Ok(meta) = fs::symlink_metadata(path) {
+        if meta.is_symlink() {
+            return TreeSize::default();
+        }
+        if meta.is_file() {
+            return TreeSize {
+                bytes: meta.len(),
+                protected_files: if is_protected_file(path) {
+                    vec![path.to_path_buf()]
+                } else {
+                    Vec::new()
+                },
+            };
+        }
+    }
+
+    let listing = match list_dir(path) {
+        Some(listing) => listing,
+        None => return TreeSize::default(),
+    };
+    let mut total = TreeSize::default();
+    for (file, size) in &listing.files {
+        if let Some(size) = size {
+            total.bytes += size;
+            if is_protected_file(file) {
+                total.protected_files.push(file.clone());
+            }
+        }
+    }
+    let children: Vec<TreeSize> = listing.dirs.par_iter().map(|dir| tree_size(dir)).collect();
+    for child in children {
+        total.bytes += child.bytes;
+        total.protected_files.extend(child.protected_files);
+    }
+    total
+}
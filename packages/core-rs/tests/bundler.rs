@@ -0,0 +1,52 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const GEMFILE: &str = r#"source "https://rubygems.org"
+
+gem "rails", "~> 7.1"
+gem 'pg'
+gem("puma")
+group :development, :test do
+  gem "rspec-rails"
+  gem "rails"
+end
+# gem "sidekiq"
+"#;
+
+#[test]
+fn locked_bundle_offers_vendor_bundle_and_its_config() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("store");
+    write(&root, "Gemfile", GEMFILE);
+    write(&root, "Gemfile.lock", "GEM\n  specs:\n");
+    write(&root, ".bundle/config", "BUNDLE_PATH: \"vendor/bundle\"\n");
+    write(&root, "vendor/bundle/ruby/3.3.0/gems/pg/pg.rb", "gem");
+    // Everything else under vendor/ is checked-in code.
+    write(&root, "vendor/javascript/chart.js", "chart");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Ruby));
+    assert_eq!(projects[0].name, "store");
+    assert_eq!(projects[0].dependency_count, 4);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from(".bundle"), PathBuf::from("vendor/bundle")]
+    );
+}
+
+#[test]
+fn bundle_without_a_lockfile_is_kept() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("store");
+    write(&root, "Gemfile", GEMFILE);
+    write(&root, "vendor/bundle/ruby/3.3.0/gems/pg/pg.rb", "gem");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].artifacts.is_empty());
+}
@@ -0,0 +1,469 @@
+mod common;
+
+use common::{cache_entry, scan_caches, write};
+use devclean_core::{build_delete_plan, remove_path, CacheKind, DeleteEntry};
+use tempfile::TempDir;
+
+#[test]
+fn rust_caches_are_labelled_and_toolchains_matched_to_pins() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(
+        home,
+        ".cargo/registry/src/index.crates.io-1/serde-1.0.0/lib.rs",
+        "src",
+    );
+    write(
+        home,
+        ".cargo/registry/cache/index.crates.io-1/serde-1.0.0.crate",
+        "crate",
+    );
+    write(home, ".cargo/git/checkouts/tool-1/abc/lib.rs", "src");
+    write(
+        home,
+        ".rustup/settings.toml",
+        "default_toolchain = \"stable-x86_64-unknown-linux-gnu\"\n",
+    );
+    write(
+        home,
+        ".rustup/toolchains/stable-x86_64-unknown-linux-gnu/bin/rustc",
+        "rustc",
+    );
+    write(
+        home,
+        ".rustup/toolchains/1.70.0-x86_64-unknown-linux-gnu/bin/rustc",
+        "rustc",
+    );
+    write(
+        home,
+        ".rustup/toolchains/nightly-2024-01-01-x86_64-unknown-linux-gnu/bin/rustc",
+        "rustc",
+    );
+    write(
+        home,
+        "projects/app/Cargo.toml",
+        "[package]\nname = \"app\"\n",
+    );
+    write(
+        home,
+        "projects/app/rust-toolchain.toml",
+        "[toolchain]\nchannel = \"1.70.0\"\n",
+    );
+
+    let projects = scan_caches(home, &[]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let sources = entry(".cargo/registry/src");
+    assert_eq!(sources.name, "Cargo registry sources");
+    assert_eq!(sources.cache_kind, Some(CacheKind::Derived));
+    assert_eq!(
+        entry(".cargo/registry/cache").name,
+        "Cargo registry downloads"
+    );
+    assert_eq!(entry(".cargo/git/checkouts").name, "Cargo git checkouts");
+    assert!(cache_entry(&projects, home, ".cargo/registry/index").is_none());
+
+    let stable = entry(".rustup/toolchains/stable-x86_64-unknown-linux-gnu");
+    assert_eq!(
+        stable.name,
+        "Rust toolchain - stable-x86_64-unknown-linux-gnu"
+    );
+    assert_eq!(stable.cache_kind, Some(CacheKind::Toolchain));
+    assert_eq!(stable.in_use, Some(true));
+    assert_eq!(
+        entry(".rustup/toolchains/1.70.0-x86_64-unknown-linux-gnu").in_use,
+        Some(true)
+    );
+    assert_eq!(
+        entry(".rustup/toolchains/nightly-2024-01-01-x86_64-unknown-linux-gnu").in_use,
+        Some(false)
+    );
+}
+
+#[test]
+fn python_caches_keep_their_own_labels_inside_the_system_cache() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(home, ".cache/pip/http/a/b/entry", "wheel");
+    write(home, ".cache/pypoetry/cache/repositories/pypi/x", "meta");
+    write(home, ".cache/pypoetry/artifacts/ab/cd/pkg.whl", "wheel");
+    write(home, "tools/uv/wheels-v1/pkg.whl", "wheel");
+    write(home, ".local/pipx/venvs/black/pyvenv.cfg", "home = /usr\n");
+    write(home, "miniconda3/pkgs/numpy-1.26.0/info/index.json", "{}");
+
+    let projects = scan_caches(home, &[("UV_CACHE_DIR", "tools/uv")]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let pip = entry(".cache/pip");
+    assert_eq!(pip.name, "pip cache");
+    assert_eq!(pip.cache_kind, Some(CacheKind::Download));
+    assert_eq!(entry(".cache/pypoetry/cache").name, "Poetry cache");
+    assert_eq!(entry(".cache/pypoetry/artifacts").name, "Poetry artifacts");
+    assert_eq!(entry("tools/uv").name, "uv cache");
+    assert!(cache_entry(&projects, home, ".cache/uv").is_none());
+    let pipx = entry(".local/pipx/venvs");
+    assert_eq!(pipx.name, "pipx environments");
+    assert_eq!(pipx.cache_kind, Some(CacheKind::Toolchain));
+    assert_eq!(entry("miniconda3/pkgs").name, "conda packages - miniconda3");
+}
+
+#[test]
+fn jvm_caches_skip_commented_settings_and_match_gradle_pins() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(
+        home,
+        ".m2/settings.xml",
+        "<settings>\n  <!-- <localRepository>/mnt/old/m2</localRepository> -->\n</settings>\n",
+    );
+    write(
+        home,
+        ".m2/repository/org/slf4j/slf4j-api/2.0.9/x.jar",
+        "jar",
+    );
+    write(home, ".gradle/caches/8.5/transforms/x", "x");
+    write(home, ".gradle/caches/7.6/transforms/x", "x");
+    write(home, ".gradle/caches/modules-2/files-2.1/x.jar", "jar");
+    write(
+        home,
+        ".gradle/wrapper/dists/gradle-8.5-bin/abc/gradle-8.5/bin/gradle",
+        "gradle",
+    );
+    write(
+        home,
+        ".gradle/wrapper/dists/gradle-7.6-all/def/gradle-7.6/bin/gradle",
+        "gradle",
+    );
+    write(home, ".gradle/daemon/8.5/daemon.log", "log");
+    write(
+        home,
+        "projects/app/settings.gradle",
+        "rootProject.name = 'app'\n",
+    );
+    write(
+        home,
+        "projects/app/gradle/wrapper/gradle-wrapper.properties",
+        "distributionUrl=https\\://services.gradle.org/distributions/gradle-8.5-bin.zip\n",
+    );
+
+    let projects = scan_caches(home, &[]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let maven = entry(".m2/repository");
+    assert_eq!(maven.name, "Maven repository");
+    assert_eq!(maven.cache_kind, Some(CacheKind::Download));
+
+    let current = entry(".gradle/caches/8.5");
+    assert_eq!(current.name, "Gradle caches - 8.5");
+    assert_eq!(current.cache_kind, Some(CacheKind::Derived));
+    assert_eq!(current.in_use, Some(true));
+    assert_eq!(entry(".gradle/caches/7.6").in_use, Some(false));
+    let shared = entry(".gradle/caches/modules-2");
+    assert_eq!(shared.name, "Gradle shared caches - modules-2");
+    assert_eq!(shared.in_use, None);
+
+    let dist = entry(".gradle/wrapper/dists/gradle-8.5-bin");
+    assert_eq!(dist.name, "Gradle distribution - gradle-8.5-bin");
+    assert_eq!(dist.cache_kind, Some(CacheKind::Toolchain));
+    assert_eq!(dist.in_use, Some(true));
+    assert_eq!(
+        entry(".gradle/wrapper/dists/gradle-7.6-all").in_use,
+        Some(false)
+    );
+    assert_eq!(entry(".gradle/daemon/8.5").in_use, Some(true));
+}
+
+#[test]
+fn node_versions_are_matched_to_project_and_default_pins() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    for version in ["v16.20.2", "v18.17.0", "v20.11.0"] {
+        write(
+            home,
+            &format!(".nvm/versions/node/{version}/bin/node"),
+            "node",
+        );
+    }
+    write(home, ".nvm/alias/default", "20\n");
+    write(home, ".volta/tools/image/node/16.20.2/bin/node", "node");
+    write(home, ".volta/tools/image/node/21.6.0/bin/node", "node");
+    write(home, ".cache/node/corepack/pnpm/8.15.0/package.json", "{}");
+    write(home, ".bun/install/cache/react@18.2.0/package.json", "{}");
+    write(home, "projects/web/package.json", r#"{ "name": "web" }"#);
+    write(home, "projects/web/.nvmrc", "v18\n");
+    write(
+        home,
+        "projects/legacy/package.json",
+        r#"{ "name": "legacy", "engines": { "node": ">= 16 <17" } }"#,
+    );
+
+    let projects = scan_caches(home, &[]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let pinned = entry(".nvm/versions/node/v18.17.0");
+    assert_eq!(pinned.name, "nvm Node - v18.17.0");
+    assert_eq!(pinned.cache_kind, Some(CacheKind::Toolchain));
+    assert_eq!(pinned.in_use, Some(true));
+    // Pinned by the nvm default alias rather than a project.
+    assert_eq!(entry(".nvm/versions/node/v20.11.0").in_use, Some(true));
+    assert_eq!(entry(".nvm/versions/node/v16.20.2").in_use, Some(true));
+    assert_eq!(
+        entry(".volta/tools/image/node/16.20.2").name,
+        "Volta Node - 16.20.2"
+    );
+    assert_eq!(entry(".volta/tools/image/node/21.6.0").in_use, Some(false));
+
+    let corepack = entry(".cache/node/corepack");
+    assert_eq!(corepack.name, "corepack cache");
+    assert_eq!(corepack.cache_kind, Some(CacheKind::Download));
+    assert_eq!(entry(".bun/install/cache").name, "bun install cache");
+}
+
+#[test]
+fn browser_binaries_are_matched_to_the_versions_projects_install() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(
+        home,
+        "projects/e2e/package.json",
+        r#"{
+            "name": "e2e",
+            "devDependencies": {
+                "@playwright/test": "1.41.0",
+                "puppeteer": "21.7.0",
+                "cypress": "^13.6.0",
+                "electron": "^28.0.0"
+            }
+        }"#,
+    );
+    write(
+        home,
+        "projects/e2e/node_modules/playwright-core/browsers.json",
+        r#"{ "browsers": [
+            { "name": "chromium", "revision": "1097" },
+            { "name": "firefox-beta", "revision": "1438" }
+        ] }"#,
+    );
+    write(
+        home,
+        "projects/e2e/node_modules/puppeteer/lib/cjs/puppeteer/revisions.js",
+        "exports.PUPPETEER_REVISIONS = { chrome: '120.0.6099.109', firefox: 'latest' };\n",
+    );
+    write(home, ".cache/ms-playwright/chromium-1097/chrome", "bin");
+    write(home, ".cache/ms-playwright/chromium-1091/chrome", "bin");
+    write(
+        home,
+        ".cache/ms-playwright/firefox_beta-1438/firefox",
+        "bin",
+    );
+    write(home, ".cache/ms-playwright/.links/abc", "link");
+    write(
+        home,
+        ".cache/puppeteer/chrome/linux-120.0.6099.109/chrome",
+        "bin",
+    );
+    write(
+        home,
+        ".cache/puppeteer/chrome/linux-119.0.6045.105/chrome",
+        "bin",
+    );
+    write(home, ".cache/Cypress/13.6.4/Cypress/Cypress", "bin");
+    write(home, ".cache/Cypress/12.17.4/Cypress/Cypress", "bin");
+    write(
+        home,
+        ".cache/electron/0a1b2c/electron-v28.2.0-linux-x64.zip",
+        "zip",
+    );
+    write(
+        home,
+        ".cache/electron/3d4e5f/electron-v25.9.8-linux-x64.zip",
+        "zip",
+    );
+
+    let projects = scan_caches(home, &[]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let chromium = entry(".cache/ms-playwright/chromium-1097");
+    assert_eq!(chromium.name, "Playwright browser - chromium-1097");
+    assert_eq!(chromium.cache_kind, Some(CacheKind::Download));
+    assert_eq!(chromium.in_use, Some(true));
+    assert_eq!(
+        entry(".cache/ms-playwright/firefox_beta-1438").in_use,
+        Some(true)
+    );
+    assert_eq!(
+        entry(".cache/ms-playwright/chromium-1091").in_use,
+        Some(false)
+    );
+    assert!(cache_entry(&projects, home, ".cache/ms-playwright/.links").is_none());
+
+    let chrome = entry(".cache/puppeteer/chrome/linux-120.0.6099.109");
+    assert_eq!(chrome.name, "Puppeteer chrome - linux-120.0.6099.109");
+    assert_eq!(chrome.in_use, Some(true));
+    assert_eq!(
+        entry(".cache/puppeteer/chrome/linux-119.0.6045.105").in_use,
+        Some(false)
+    );
+
+    assert_eq!(entry(".cache/Cypress/13.6.4").in_use, Some(true));
+    assert_eq!(entry(".cache/Cypress/12.17.4").in_use, Some(false));
+
+    let electron = entry(".cache/electron/0a1b2c");
+    assert_eq!(electron.name, "Electron binary - v28.2.0");
+    assert_eq!(electron.in_use, Some(true));
+    assert_eq!(entry(".cache/electron/3d4e5f").in_use, Some(false));
+}
+
+#[test]
+fn go_caches_follow_gopath_and_the_user_cache_dir() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(
+        home,
+        "gopath/pkg/mod/github.com/lib/pq@v1.10.9/conn.go",
+        "package pq",
+    );
+    write(home, "go/pkg/mod/cache/download/x", "zip");
+    write(home, ".cache/go-build/00/abc-d", "object");
+
+    let projects = scan_caches(home, &[("GOPATH", "gopath")]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let modules = entry("gopath/pkg/mod");
+    assert_eq!(modules.name, "Go module cache");
+    assert_eq!(modules.cache_kind, Some(CacheKind::Download));
+    assert!(cache_entry(&projects, home, "go/pkg/mod").is_none());
+    let build = entry(".cache/go-build");
+    assert_eq!(build.name, "Go build cache");
+    assert_eq!(build.cache_kind, Some(CacheKind::Derived));
+}
+
+#[cfg(unix)]
+#[test]
+fn read_only_module_cache_is_removed() {
+    use std::fs;
+    use std::os::unix::fs::{symlink, PermissionsExt};
+
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("mod");
+    let module = cache.join("github.com/lib/pq@v1.10.9");
+    write(&module, "conn.go", "package pq");
+    write(dir.path(), "outside/keep.txt", "keep");
+    symlink(dir.path().join("outside"), module.join("outside")).unwrap();
+    for path in [module.join("conn.go"), dir.path().join("outside/keep.txt")] {
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o444)).unwrap();
+    }
+    for path in [&module, &cache.join("github.com"), &cache] {
+        fs::set_permissions(path, fs::Permissions::from_mode(0o555)).unwrap();
+    }
+
+    remove_path(&cache).unwrap();
+    assert!(!cache.exists());
+    // The symlink is removed without touching what it points at.
+    let kept = dir.path().join("outside/keep.txt");
+    assert!(kept.exists());
+    assert!(fs::metadata(&kept).unwrap().permissions().readonly());
+}
+
+#[test]
+fn overlapping_caches_are_nested_and_counted_once() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(home, ".yarn/cache/left-pad-npm-1.3.0.zip", "zipped");
+    write(home, ".yarn/berry/install-state.gz", "state");
+    write(
+        home,
+        ".cache/puppeteer/chrome/linux-120.0.6099.109/chrome",
+        "bin",
+    );
+    write(home, ".cache/pip/http/entry", "wheel");
+
+    let projects = scan_caches(home, &[]);
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    let yarn = entry(".yarn");
+    assert_eq!(yarn.parent_id, None);
+    assert_eq!(entry(".yarn/cache").parent_id, Some(yarn.id.clone()));
+    let puppeteer = entry(".cache/puppeteer");
+    assert_eq!(puppeteer.name, "System cache - puppeteer");
+    assert_eq!(
+        entry(".cache/puppeteer/chrome/linux-120.0.6099.109").parent_id,
+        Some(puppeteer.id.clone())
+    );
+    assert_eq!(entry(".cache/pip").parent_id, None);
+
+    let entries: Vec<DeleteEntry> = [".yarn/cache", ".yarn"]
+        .iter()
+        .map(|path| DeleteEntry {
+            path: home.join(path),
+            is_cache: true,
+        })
+        .collect();
+    let plan = build_delete_plan(&entries, false, None, 1);
+    assert_eq!(plan.items.len(), 1);
+    assert_eq!(plan.items[0].path, home.join(".yarn").to_string_lossy());
+    assert_eq!(plan.total_bytes, yarn.size_bytes);
+    assert_eq!(plan.total_bytes, 11);
+}
+
+#[test]
+fn rust_caches_follow_cargo_and_rustup_homes() {
+    let dir = TempDir::new().unwrap();
+    let home = dir.path();
+    write(
+        home,
+        "tools/cargo/registry/cache/index-1/serde.crate",
+        "crate",
+    );
+    write(home, ".cargo/registry/cache/index-1/serde.crate", "crate");
+    write(
+        home,
+        "tools/rustup/settings.toml",
+        "[overrides]\n\"/work/old\" = \"1.65.0-x86_64-unknown-linux-gnu\"\n",
+    );
+    for toolchain in [
+        "1.65.0-x86_64-unknown-linux-gnu",
+        "1.74.0-x86_64-unknown-linux-gnu",
+        "nightly-x86_64-unknown-linux-gnu",
+    ] {
+        write(
+            home,
+            &format!("tools/rustup/toolchains/{toolchain}/bin/rustc"),
+            "rustc",
+        );
+    }
+    write(
+        home,
+        "projects/cli/Cargo.toml",
+        "[package]\nname = \"cli\"\n",
+    );
+    write(home, "projects/cli/rust-toolchain", "nightly\n");
+
+    let projects = scan_caches(
+        home,
+        &[
+            ("CARGO_HOME", "tools/cargo"),
+            ("RUSTUP_HOME", "tools/rustup"),
+        ],
+    );
+    let entry = |path: &str| cache_entry(&projects, home, path).unwrap();
+
+    assert_eq!(
+        entry("tools/cargo/registry/cache").name,
+        "Cargo registry downloads"
+    );
+    assert!(cache_entry(&projects, home, ".cargo/registry/cache").is_none());
+    let toolchains = "tools/rustup/toolchains";
+    assert_eq!(
+        entry(&format!("{toolchains}/1.65.0-x86_64-unknown-linux-gnu")).in_use,
+        Some(true)
+    );
+    assert_eq!(
+        entry(&format!("{toolchains}/nightly-x86_64-unknown-linux-gnu")).in_use,
+        Some(true)
+    );
+    assert_eq!(
+        entry(&format!("{toolchains}/1.74.0-x86_64-unknown-linux-gnu")).in_use,
+        Some(false)
+    );
+}
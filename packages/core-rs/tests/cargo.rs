@@ -0,0 +1,73 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const WORKSPACE: &str = r#"[workspace]
+members = ["crates/*"]
+exclude = ["crates/scratch"]
+
+[workspace.dependencies]
+serde = "1"
+"#;
+
+#[test]
+fn workspace_folds_its_members_and_shares_one_target() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("engine");
+    write(&root, "Cargo.toml", WORKSPACE);
+    write(&root, "Cargo.lock", "version = 3\n");
+    write(&root, "target/debug/engine", "binary");
+    write(
+        &root,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\n\n[dependencies]\nserde = { workspace = true }\ntokio = \"1\"\n",
+    );
+    write(
+        &root,
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"cli\"\n\n[dev-dependencies]\ninsta = \"1\"\n",
+    );
+    write(
+        &root,
+        "crates/scratch/Cargo.toml",
+        "[package]\nname = \"scratch\"\n",
+    );
+    write(&root, "crates/scratch/target/debug/scratch", "binary");
+
+    let mut projects = scan(dir.path());
+    projects.sort_by(|a, b| a.path.cmp(&b.path));
+    assert_eq!(projects.len(), 2);
+
+    let workspace = &projects[0];
+    assert_eq!(workspace.ecosystem, Some(Ecosystem::Rust));
+    assert_eq!(workspace.name, "engine");
+    assert_eq!(workspace.manifests.len(), 4);
+    assert_eq!(workspace.dependency_count, 3);
+    assert_eq!(
+        artifact_paths(workspace, &root),
+        vec![PathBuf::from("target")]
+    );
+
+    let scratch = &projects[1];
+    assert_eq!(scratch.name, "scratch");
+    assert_eq!(
+        artifact_paths(scratch, &root.join("crates/scratch")),
+        vec![PathBuf::from("target")]
+    );
+}
+
+#[test]
+fn target_folder_of_a_non_cargo_project_is_left_alone() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("site");
+    write(&root, "package.json", r#"{ "name": "site" }"#);
+    write(&root, "target/index.html", "<html></html>");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Node));
+    assert!(projects[0].artifacts.is_empty());
+}
@@ -0,0 +1,98 @@
+// Each test binary uses its own subset of these helpers.
+#![allow(dead_code)]
+
+use devclean_core::{scan_projects, ProjectMeta, ScanProgress};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub fn write(root: &Path, path: &str, data: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, data).unwrap();
+}
+
+pub fn scan(root: &Path) -> Vec<ProjectMeta> {
+    scan_projects(root, false, false, 1, None::<fn(ScanProgress)>).projects
+}
+
+pub fn artifact_paths(project: &ProjectMeta, root: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = project
+        .artifacts
+        .iter()
+        .map(|artifact| {
+            Path::new(&artifact.path)
+                .strip_prefix(root)
+                .unwrap()
+                .to_path_buf()
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+// Tool locations the cache scan reads from the environment. Tests clear them
+// so that only the fake home and the variables a test sets are looked at.
+const CACHE_VARIABLES: &[&str] = &[
+    "BUN_INSTALL",
+    "BUN_INSTALL_CACHE_DIR",
+    "CARGO_HOME",
+    "CONDA_PKGS_DIRS",
+    "CONDA_PREFIX",
+    "COREPACK_HOME",
+    "CYPRESS_CACHE_FOLDER",
+    "DENO_DIR",
+    "ELECTRON_CACHE",
+    "FNM_DIR",
+    "GOCACHE",
+    "GOMODCACHE",
+    "GOPATH",
+    "GRADLE_USER_HOME",
+    "NPM_CONFIG_CACHE",
+    "NVM_DIR",
+    "PIPX_HOME",
+    "PIP_CACHE_DIR",
+    "PLAYWRIGHT_BROWSERS_PATH",
+    "PNPM_STORE_PATH",
+    "POETRY_CACHE_DIR",
+    "PUPPETEER_CACHE_DIR",
+    "RUSTUP_HOME",
+    "UV_CACHE_DIR",
+    "VOLTA_HOME",
+    "YARN_CACHE_FOLDER",
+];
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// Scans `home/projects` together with the caches found under `home`, which
+/// stands in for the user's home folder. `vars` are set on top, relative to
+/// `home`.
+pub fn scan_caches(home: &Path, vars: &[(&str, &str)]) -> Vec<ProjectMeta> {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    env::set_var("HOME", home);
+    env::set_var("XDG_CACHE_HOME", home.join(".cache"));
+    env::set_var("XDG_DATA_HOME", home.join(".local/share"));
+    env::set_var("XDG_CONFIG_HOME", home.join(".config"));
+    for variable in CACHE_VARIABLES {
+        env::remove_var(variable);
+    }
+    for (variable, path) in vars {
+        env::set_var(variable, home.join(path));
+    }
+    let projects = home.join("projects");
+    fs::create_dir_all(&projects).unwrap();
+    scan_projects(&projects, false, true, 1, None::<fn(ScanProgress)>).projects
+}
+
+/// The cache entry at `path` below `home`, if the scan offered one.
+pub fn cache_entry<'a>(
+    projects: &'a [ProjectMeta],
+    home: &Path,
+    path: &str,
+) -> Option<&'a ProjectMeta> {
+    let path = home.join(path);
+    projects
+        .iter()
+        .find(|project| project.is_cache && Path::new(&project.path) == path)
+}
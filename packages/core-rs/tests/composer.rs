@@ -0,0 +1,55 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const COMPOSER_JSON: &str = r#"{
+    "name": "acme/billing",
+    "keywords": ["production"],
+    "require": {
+        "php": "^8.2",
+        "ext-json": "*",
+        "laravel/framework": "^11.0",
+        "guzzlehttp/guzzle": "^7.8"
+    },
+    "require-dev": {
+        "phpunit/phpunit": "^11.0"
+    }
+}"#;
+
+#[test]
+fn locked_composer_project_offers_its_vendor_folder() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("billing");
+    write(&root, "composer.json", COMPOSER_JSON);
+    write(&root, "composer.lock", "{}");
+    write(&root, "vendor/autoload.php", "<?php");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Php));
+    assert_eq!(projects[0].name, "acme/billing");
+    assert!(projects[0].has_startup_keyword);
+    // Platform requirements such as php and ext-json are not packages.
+    assert_eq!(projects[0].dependency_count, 3);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("vendor")]
+    );
+}
+
+#[test]
+fn vendor_folder_without_a_lockfile_is_kept() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("billing");
+    write(&root, "composer.json", COMPOSER_JSON);
+    // Without composer.lock, `composer install` may not restore the same
+    // versions, and the folder may hold committed code.
+    write(&root, "vendor/acme/patched/Client.php", "<?php");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].artifacts.is_empty());
+}
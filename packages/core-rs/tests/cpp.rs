@@ -0,0 +1,126 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::{detect_artifacts, Ecosystem};
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const CMAKE_LISTS: &str = r#"cmake_minimum_required(VERSION 3.20)
+project(Engine CXX)
+find_package(fmt REQUIRED)
+# find_package(Commented)
+FetchContent_Declare(json URL https://example.com/json.tar.xz)
+add_subdirectory(src)
+"#;
+
+#[test]
+fn cmake_project_reports_build_trees_below_it() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("engine");
+    write(&root, "CMakeLists.txt", CMAKE_LISTS);
+    write(&root, "src/CMakeLists.txt", "add_library(core core.cpp)\n");
+    write(
+        &root,
+        "build/CMakeCache.txt",
+        "CMAKE_BUILD_TYPE:STRING=Debug\n",
+    );
+    // FetchContent copies dependency sources, manifests included, into the
+    // build tree; they must not turn up as projects of their own.
+    write(
+        &root,
+        "build/_deps/json-src/CMakeLists.txt",
+        "project(json)\n",
+    );
+    write(&root, "out/build/debug/CMakeCache.txt", "cache");
+    write(&root, "builddir/meson-private/coredata.dat", "meson");
+    // Any folder can be a build tree, but not one inside vendored code.
+    write(&root, "third_party/zlib/build/CMakeCache.txt", "cache");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Cpp));
+    assert_eq!(projects[0].name, "Engine");
+    assert_eq!(projects[0].dependency_count, 2);
+    let expected = vec![
+        PathBuf::from("build"),
+        PathBuf::from("builddir"),
+        PathBuf::from("out/build/debug"),
+    ];
+    assert_eq!(artifact_paths(&projects[0], &root), expected);
+
+    let mut detected: Vec<PathBuf> = detect_artifacts(&root)
+        .iter()
+        .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+        .collect();
+    detected.sort();
+    assert_eq!(detected, expected);
+}
+
+#[test]
+fn build_tree_outside_a_cpp_project_is_scanned_as_usual() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("site");
+    write(&root, "package.json", r#"{ "name": "site" }"#);
+    write(&root, "native/CMakeCache.txt", "cache");
+    write(
+        &root,
+        "native/tool/Cargo.toml",
+        "[package]\nname = \"tool\"\n",
+    );
+    write(&root, "native/tool/target/debug/tool", "bin");
+
+    let projects = scan(dir.path());
+    let site = projects
+        .iter()
+        .find(|project| project.name == "site")
+        .unwrap();
+    assert!(site.artifacts.is_empty());
+    let tool = projects
+        .iter()
+        .find(|project| project.name == "tool")
+        .unwrap();
+    assert_eq!(tool.ecosystem, Some(Ecosystem::Rust));
+    assert_eq!(
+        artifact_paths(tool, &root.join("native/tool")),
+        vec![PathBuf::from("target")]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn bazel_workspace_offers_its_output_base_and_links() {
+    use std::os::unix::fs::symlink;
+
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("app");
+    write(
+        &root,
+        "MODULE.bazel",
+        "module(name = \"app\")\nbazel_dep(name = \"rules_cc\", version = \"0.0.9\")\n",
+    );
+    write(&root, "src/main.cc", "int main() {}");
+    let base = dir.path().join("cache/_bazel_user/0123abcd");
+    let execroot = base.join("execroot/_main");
+    write(&execroot, "bazel-out/k8-fastbuild/bin/app", "binary");
+    symlink(&execroot, root.join("bazel-app")).unwrap();
+    symlink(execroot.join("bazel-out"), root.join("bazel-out")).unwrap();
+    // Links that lead anywhere else are not Bazel's to clean up.
+    write(dir.path(), "elsewhere/notes.txt", "notes");
+    symlink(dir.path().join("elsewhere"), root.join("bazel-notes")).unwrap();
+
+    let projects = scan(&root);
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Cpp));
+    assert_eq!(projects[0].name, "app");
+    assert_eq!(projects[0].dependency_count, 1);
+    let mut artifacts: Vec<PathBuf> = projects[0]
+        .artifacts
+        .iter()
+        .map(|artifact| PathBuf::from(&artifact.path))
+        .collect();
+    artifacts.sort();
+    assert_eq!(
+        artifacts,
+        vec![root.join("bazel-app"), root.join("bazel-out"), base]
+    );
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const PUBSPEC: &str = r#"name: "weather_app"
+description: A Flutter app.
+
+environment:
+  sdk: ">=3.0.0 <4.0.0"
+
+dependencies:
+  flutter:
+    sdk: flutter
+  http: ^1.2.0 # networking
+  # provider: ^6.0.0
+
+dev_dependencies:
+  flutter_test:
+    sdk: flutter
+  flutter_lints: ^3.0.0
+"#;
+
+#[test]
+fn flutter_app_reports_tool_build_and_ephemeral_folders() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("weather");
+    write(&root, "pubspec.yaml", PUBSPEC);
+    write(&root, "pubspec.lock", "packages:\n");
+    write(&root, "lib/main.dart", "void main() {}");
+    write(&root, ".dart_tool/package_config.json", "{}");
+    write(&root, "build/app/outputs/app.apk", "apk");
+    write(
+        &root,
+        "ios/Flutter/ephemeral/flutter_lldb_helper.py",
+        "helper",
+    );
+    write(&root, "linux/flutter/ephemeral/.plugin_symlinks/x", "x");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Dart));
+    assert_eq!(projects[0].name, "weather_app");
+    assert_eq!(projects[0].dependency_count, 4);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from(".dart_tool"),
+            PathBuf::from("build"),
+            PathBuf::from("ios/Flutter/ephemeral"),
+            PathBuf::from("linux/flutter/ephemeral"),
+        ]
+    );
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::{build_delete_plan, DeleteEntry, Ecosystem};
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "Api", "src\Api\Api.csproj", "{1}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "Tests", "tests\Tests\Tests.fsproj", "{2}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "Docs", "Docs", "{3}"
+EndProject
+"#;
+
+const API_PROJECT: &str = r#"<Project Sdk="Microsoft.NET.Sdk.Web">
+  <ItemGroup>
+    <PackageReference Include="Serilog" Version="3.1.1" />
+    <!-- <PackageReference Include="Commented.Out" Version="1.0.0" /> -->
+    <PackageReference Include="Dapper" Version="2.1.0" />
+  </ItemGroup>
+</Project>
+"#;
+
+const TESTS_PROJECT: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="2.6.0" />
+    <PackageReference Include="serilog" Version="3.1.1" />
+  </ItemGroup>
+</Project>
+"#;
+
+#[test]
+fn solution_groups_its_projects_and_their_build_folders() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Shop");
+    write(&root, "Shop.sln", SOLUTION);
+    write(&root, "src/Api/Api.csproj", API_PROJECT);
+    write(&root, "src/Api/bin/Debug/Api.dll", "dll");
+    write(&root, "src/Api/obj/project.assets.json", "{}");
+    write(&root, "tests/Tests/Tests.fsproj", TESTS_PROJECT);
+    write(&root, "tests/Tests/obj/project.assets.json", "{}");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    let solution = &projects[0];
+    assert_eq!(solution.ecosystem, Some(Ecosystem::Dotnet));
+    assert_eq!(solution.name, "Shop");
+    assert_eq!(solution.manifests.len(), 3);
+    // Package names are compared case-insensitively across the solution.
+    assert_eq!(solution.dependency_count, 3);
+    assert_eq!(
+        artifact_paths(solution, &root),
+        vec![
+            PathBuf::from("src/Api/bin"),
+            PathBuf::from("src/Api/obj"),
+            PathBuf::from("tests/Tests/obj"),
+        ]
+    );
+}
+
+#[test]
+fn lone_project_reports_only_existing_build_folders() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Tool");
+    write(&root, "Tool.csproj", API_PROJECT);
+    write(&root, "obj/project.assets.json", "{}");
+    write(&root, "Program.cs", "class Program {}");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "Tool");
+    assert_eq!(projects[0].dependency_count, 2);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("obj")]
+    );
+}
+
+#[test]
+fn deps_only_plan_removes_build_folders_of_solution_members() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Shop");
+    write(&root, "Shop.sln", SOLUTION);
+    write(&root, "src/Api/Api.csproj", API_PROJECT);
+    write(&root, "src/Api/bin/Debug/Api.dll", "dll");
+    write(&root, "tests/Tests/Tests.fsproj", TESTS_PROJECT);
+    write(&root, "tests/Tests/bin/Debug/Tests.dll", "dll");
+
+    let entries = [DeleteEntry {
+        path: root.clone(),
+        is_cache: false,
+    }];
+    let plan = build_delete_plan(&entries, true, None, 1);
+    let mut paths: Vec<PathBuf> = plan
+        .items
+        .iter()
+        .map(|item| PathBuf::from(&item.path))
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![root.join("src/Api/bin"), root.join("tests/Tests/bin")]
+    );
+    assert_eq!(plan.total_bytes, 6);
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const GO_MOD: &str = r#"module github.com/acme/server/v2
+
+go 1.22
+
+require github.com/go-chi/chi/v5 v5.0.12 // indirect
+
+require (
+	github.com/lib/pq v1.10.9
+	golang.org/x/sync v0.6.0
+)
+"#;
+
+const ELF: &str = "\x7fELF binary";
+
+#[test]
+fn module_reports_vendor_and_built_binaries() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("server");
+    write(&root, "go.mod", GO_MOD);
+    write(&root, "go.sum", "github.com/lib/pq v1.10.9 h1:x\n");
+    write(&root, "vendor/modules.txt", "# github.com/lib/pq v1.10.9\n");
+    write(&root, "main.go", "package main\n\nfunc main() {}\n");
+    // Named after the module path without its /v2 suffix.
+    write(&root, "server", ELF);
+    write(
+        &root,
+        "cmd/migrate/main.go",
+        "package main\n\nfunc main() {}\n",
+    );
+    write(&root, "migrate.exe", "MZ but only text");
+    write(&root, "migrate", ELF);
+    // cmd/tool is no main package, so nothing builds a tool binary.
+    write(&root, "cmd/tool/main.go", "package tool\n");
+    write(&root, "tool", ELF);
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Go));
+    assert_eq!(projects[0].name, "github.com/acme/server/v2");
+    assert_eq!(projects[0].dependency_count, 3);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from("migrate"),
+            PathBuf::from("server"),
+            PathBuf::from("vendor"),
+        ]
+    );
+}
+
+#[test]
+fn vendor_without_go_sum_is_left_alone() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("lib");
+    write(&root, "go.mod", "module example.com/lib\n\ngo 1.22\n");
+    write(&root, "vendor/patched/fork.go", "package patched\n");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].artifacts.is_empty());
+}
+
+#[test]
+fn workspace_collects_vendor_folders_of_its_modules() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("mono");
+    write(
+        &root,
+        "go.work",
+        "go 1.22\n\nuse (\n\t./api\n\t./worker\n)\n",
+    );
+    write(
+        &root,
+        "api/go.mod",
+        "module example.com/api\n\nrequire golang.org/x/sync v0.6.0\n",
+    );
+    write(&root, "api/go.sum", "golang.org/x/sync v0.6.0 h1:x\n");
+    write(
+        &root,
+        "api/vendor/modules.txt",
+        "# golang.org/x/sync v0.6.0\n",
+    );
+    write(
+        &root,
+        "worker/go.mod",
+        "module example.com/worker\n\nrequire golang.org/x/sync v0.6.0\n",
+    );
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].manifests.len(), 3);
+    assert_eq!(projects[0].dependency_count, 1);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("api/vendor")]
+    );
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const CABAL: &str = r#"cabal-version: 3.0
+name:          parser
+version:       0.1.0.0
+
+library
+  exposed-modules: Parser
+  build-depends:   base >=4.14 && <5,
+                   megaparsec ^>=9.6,
+                   text
+  -- build-depends: lens
+
+test-suite spec
+  build-depends: base, parser, hspec
+"#;
+
+#[test]
+fn stack_project_offers_stack_work_of_root_and_packages() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("compiler");
+    write(
+        &root,
+        "stack.yaml",
+        "resolver: lts-22.0\npackages:\n  - .\n  - parser\nextra-deps:\n  - acme-missiles-0.3\n",
+    );
+    write(&root, ".stack-work/install/x/bin/compiler", "bin");
+    write(&root, "parser/parser.cabal", CABAL);
+    write(&root, "parser/.stack-work/dist/x/build/Parser.o", "obj");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Haskell));
+    // base, megaparsec, text, parser, hspec and acme-missiles.
+    assert_eq!(projects[0].dependency_count, 6);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from(".stack-work"),
+            PathBuf::from("parser/.stack-work"),
+        ]
+    );
+}
+
+#[test]
+fn cabal_package_offers_dist_newstyle() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("parser");
+    write(&root, "parser.cabal", CABAL);
+    write(&root, "dist-newstyle/cache/plan.json", "{}");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "parser");
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("dist-newstyle")]
+    );
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::{build_delete_plan, evaluate_heuristic, DeleteEntry, Ecosystem};
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const MAIN_TF: &str = r#"module "network" {
+  source = "./modules/network"
+}
+
+module "vpc" {
+  source  = "terraform-aws-modules/vpc/aws" # registry module
+  version = "5.0.0"
+}
+"#;
+
+#[test]
+fn terraform_root_offers_providers_but_flags_its_state() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("infra");
+    write(&root, "main.tf", MAIN_TF);
+    write(&root, "terraform.tfstate", "{}");
+    write(&root, "terraform.tfstate.backup", "{}");
+    write(
+        &root,
+        ".terraform/providers/aws/terraform-provider-aws",
+        "bin",
+    );
+    write(&root, ".terraform/modules/modules.json", "{}");
+    write(
+        &root,
+        "modules/network/main.tf",
+        "resource \"aws_vpc\" \"main\" {}\n",
+    );
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    let project = &projects[0];
+    assert_eq!(project.ecosystem, Some(Ecosystem::Infra));
+    assert_eq!(project.manifests.len(), 2);
+    assert_eq!(project.dependency_count, 2);
+    assert!(project.has_state_file);
+    assert_eq!(
+        artifact_paths(project, &root),
+        vec![PathBuf::from(".terraform/providers")]
+    );
+    let risk = evaluate_heuristic(project);
+    assert!(risk
+        .reasons
+        .contains(&"Infrastructure state file present".to_string()));
+}
+
+#[test]
+fn artifacts_holding_state_files_are_never_offered() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("api");
+    write(
+        &root,
+        "serverless.yml",
+        "service: api\nplugins:\n  - serverless-offline\n  - serverless-esbuild\n",
+    );
+    write(&root, ".serverless/api.zip", "zip");
+    // A state file that ended up in the deploy folder must survive.
+    write(&root, ".serverless/terraform.tfstate", "{}");
+    write(
+        &root,
+        "cdk/cdk.json",
+        "{ \"app\": \"npx ts-node bin/app.ts\" }",
+    );
+    write(&root, "cdk/cdk.out/manifest.json", "{}");
+
+    let projects = scan(dir.path());
+    let api = projects
+        .iter()
+        .find(|project| project.name == "api")
+        .unwrap();
+    assert_eq!(api.ecosystem, Some(Ecosystem::Infra));
+    assert_eq!(api.dependency_count, 2);
+    assert!(api.artifacts.is_empty());
+    let cdk = projects
+        .iter()
+        .find(|project| project.name == "cdk")
+        .unwrap();
+    assert_eq!(
+        artifact_paths(cdk, &root.join("cdk")),
+        vec![PathBuf::from("cdk.out")]
+    );
+
+    let entries = [DeleteEntry {
+        path: root.clone(),
+        is_cache: false,
+    }];
+    let plan = build_delete_plan(&entries, true, None, 1);
+    assert!(plan.items.is_empty());
+    assert!(root.join(".serverless/terraform.tfstate").exists());
+}
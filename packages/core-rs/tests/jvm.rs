@@ -0,0 +1,106 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const PARENT_POM: &str = r#"<project>
+  <parent>
+    <artifactId>spring-boot-starter-parent</artifactId>
+  </parent>
+  <artifactId>shop-parent</artifactId>
+  <name>Shop</name>
+  <modules>
+    <module>api</module>
+    <!-- <module>legacy</module> -->
+  </modules>
+  <dependencies>
+    <dependency>
+      <groupId>org.slf4j</groupId>
+      <artifactId>slf4j-api</artifactId>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+const API_POM: &str = r#"<project>
+  <artifactId>api</artifactId>
+  <dependencies>
+    <dependency>
+      <groupId>org.springframework.boot</groupId>
+      <artifactId>spring-boot-starter-web</artifactId>
+    </dependency>
+    <dependency>
+      <groupId>org.slf4j</groupId>
+      <artifactId>slf4j-api</artifactId>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+#[test]
+fn maven_build_reports_target_of_each_module() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("shop");
+    write(&root, "pom.xml", PARENT_POM);
+    write(&root, "target/classes/App.class", "class");
+    write(&root, "api/pom.xml", API_POM);
+    write(&root, "api/target/api.jar", "jar");
+    write(&root, "legacy/target/old.jar", "jar");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Jvm));
+    assert_eq!(projects[0].name, "Shop");
+    assert_eq!(projects[0].dependency_count, 2);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("api/target"), PathBuf::from("target")]
+    );
+}
+
+#[test]
+fn gradle_build_reports_build_folders_of_included_projects() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("android");
+    write(
+        &root,
+        "settings.gradle.kts",
+        "rootProject.name = \"Notes\"\ninclude(\":app\",\n    \":core:data\")\n",
+    );
+    write(
+        &root,
+        "build.gradle.kts",
+        "plugins { id(\"com.android.application\") }\n",
+    );
+    write(&root, ".gradle/8.5/checksums/checksums.lock", "lock");
+    write(
+        &root,
+        "app/build.gradle.kts",
+        "dependencies {\n    implementation(\"androidx.core:core-ktx:1.12.0\")\n    implementation(project(\":core:data\"))\n    testImplementation(libs.junit)\n}\n",
+    );
+    write(&root, "app/build/outputs/apk/app.apk", "apk");
+    write(
+        &root,
+        "core/data/build.gradle",
+        "dependencies {\n    api 'com.squareup.retrofit2:retrofit:2.9.0'\n}\n",
+    );
+    write(&root, "core/data/build/libs/data.jar", "jar");
+    // A build folder outside the included projects is left alone.
+    write(&root, "docs/build/index.html", "<html></html>");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Jvm));
+    assert_eq!(projects[0].name, "Notes");
+    assert_eq!(projects[0].dependency_count, 4);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from(".gradle"),
+            PathBuf::from("app/build"),
+            PathBuf::from("core/data/build"),
+        ]
+    );
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const UMBRELLA: &str = r#"defmodule Shop.Umbrella.MixProject do
+  use Mix.Project
+
+  def project do
+    [apps_path: "apps", version: "0.1.0", deps: deps()]
+  end
+
+  defp deps do
+    [{:credo, "~> 1.7", only: [:dev, :test]}]
+  end
+end
+"#;
+
+const WEB_APP: &str = r#"defmodule Web.MixProject do
+  use Mix.Project
+
+  def project do
+    [app: :web, version: "0.1.0", deps: deps()]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7"},
+      {:jason, "~> 1.4"},
+      {:credo, "~> 1.7", only: [:dev]}
+    ]
+  end
+end
+"#;
+
+#[test]
+fn umbrella_project_folds_its_apps_and_offers_build_and_deps() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("shop");
+    write(&root, "mix.exs", UMBRELLA);
+    write(&root, "mix.lock", "%{}");
+    write(&root, "_build/dev/lib/web/ebin/web.app", "app");
+    write(
+        &root,
+        "deps/phoenix/mix.exs",
+        "defmodule Phoenix.MixProject do end",
+    );
+    write(&root, "apps/web/mix.exs", WEB_APP);
+    write(&root, "apps/web/lib/web.ex", "defmodule Web do end");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Elixir));
+    assert_eq!(projects[0].name, "shop");
+    assert_eq!(projects[0].dependency_count, 3);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("_build"), PathBuf::from("deps")]
+    );
+}
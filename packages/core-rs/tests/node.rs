@@ -0,0 +1,72 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn framework_caches_follow_the_declared_dependencies() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("shop");
+    write(
+        &root,
+        "package.json",
+        r#"{
+            "name": "shop",
+            "scripts": { "build": "next build && tsc --outDir=server/dist" },
+            "dependencies": { "next": "14.1.0", "react": "18.2.0" },
+            "devDependencies": { "turbo": "1.12.0", "@storybook/react": "7.6.0" }
+        }"#,
+    );
+    write(&root, "node_modules/next/package.json", "{}");
+    write(&root, ".next/cache/webpack/client.pack", "pack");
+    write(&root, ".turbo/cookies/1.cookie", "cookie");
+    write(&root, "storybook-static/index.html", "<html></html>");
+    write(&root, "server/dist/index.js", "compiled");
+    // No dependency writes these, so they are left alone.
+    write(&root, ".nuxt/dist/server.mjs", "nuxt");
+    write(&root, ".svelte-kit/output/index.js", "svelte");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Node));
+    assert_eq!(projects[0].dependency_count, 4);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from(".next"),
+            PathBuf::from(".turbo"),
+            PathBuf::from("node_modules"),
+            PathBuf::from("server/dist"),
+            PathBuf::from("storybook-static"),
+        ]
+    );
+}
+
+#[test]
+fn build_outputs_are_offered_only_for_the_tool_that_writes_them() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("desktop");
+    write(
+        &root,
+        "package.json",
+        r#"{
+            "name": "desktop",
+            "scripts": { "build": "vite build && electron-builder --publish never" },
+            "devDependencies": { "vite": "5.1.0", "electron-builder": "24.9.0" }
+        }"#,
+    );
+    write(&root, "dist/main.js", "bundle");
+    write(&root, ".vite/deps/react.js", "prebundled");
+    // electron-builder reads icons and entitlements from build/.
+    write(&root, "build/icon.icns", "icon");
+    write(&root, "build/entitlements.mac.plist", "plist");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from(".vite"), PathBuf::from("dist")]
+    );
+}
@@ -0,0 +1,369 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use devclean_core::{build_delete_plan, execute_prune, plan_prune, DeleteEntry, PruneOptions};
+use sha2::{Digest, Sha512};
+use std::fs::{self, FileTimes};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tempfile::TempDir;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn options(max_age_days: u64) -> PruneOptions {
+    PruneOptions {
+        max_age_days,
+        project_paths: Vec::new(),
+        excluded_paths: Vec::new(),
+    }
+}
+
+fn age(path: &Path, days: u32) {
+    let time = SystemTime::now() - DAY * days;
+    let times = FileTimes::new().set_accessed(time).set_modified(time);
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_times(times)
+        .unwrap();
+}
+
+fn integrity(data: &[u8]) -> (String, String) {
+    let digest = Sha512::digest(data);
+    (
+        format!("sha512-{}", STANDARD.encode(digest)),
+        hex::encode(digest),
+    )
+}
+
+// Writes a blob into content-v2 and appends its index line to `bucket`.
+fn add_npm_entry(cache: &Path, bucket: &str, key: &str, data: &[u8], days_old: u32) -> PathBuf {
+    let (integrity, hex) = integrity(data);
+    let blob = cache
+        .join("content-v2/sha512")
+        .join(&hex[..2])
+        .join(&hex[2..4])
+        .join(&hex[4..]);
+    fs::create_dir_all(blob.parent().unwrap()).unwrap();
+    fs::write(&blob, data).unwrap();
+    age(&blob, days_old);
+
+    let time = (SystemTime::now() - DAY * days_old)
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let line = format!(
+        "\n{}\t{}",
+        hex::encode(Sha512::digest(key.as_bytes())),
+        serde_json::json!({ "key": key, "integrity": integrity, "time": time })
+    );
+    let bucket = cache.join("index-v5").join(bucket);
+    fs::create_dir_all(bucket.parent().unwrap()).unwrap();
+    let mut data = fs::read_to_string(&bucket).unwrap_or_default();
+    data.push_str(&line);
+    fs::write(&bucket, data).unwrap();
+    blob
+}
+
+fn bucket_keys(cache: &Path, bucket: &str) -> Vec<String> {
+    let data = fs::read_to_string(cache.join("index-v5").join(bucket)).unwrap_or_default();
+    data.lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(_, json)| {
+            let value: serde_json::Value = serde_json::from_str(json).unwrap();
+            value["key"].as_str().unwrap().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn npm_plan_counts_entries_older_than_the_cutoff() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("_cacache");
+    add_npm_entry(
+        &cache,
+        "aa/bb/old",
+        "make-fetch-happen:request-cache:old",
+        b"old tarball",
+        90,
+    );
+    add_npm_entry(
+        &cache,
+        "cc/dd/new",
+        "make-fetch-happen:request-cache:new",
+        b"new",
+        2,
+    );
+
+    let summary = plan_prune(dir.path(), &options(30)).unwrap();
+    assert_eq!(summary.entry_count, 2);
+    assert_eq!(summary.pruned_entries, 1);
+    assert_eq!(summary.reclaimed_bytes, 11);
+}
+
+#[test]
+fn npm_plan_treats_unindexed_blobs_as_stale() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("_cacache");
+    add_npm_entry(
+        &cache,
+        "aa/bb/new",
+        "make-fetch-happen:request-cache:new",
+        b"new",
+        1,
+    );
+    let orphan = cache.join("content-v2/sha512/ff/ee/orphan");
+    fs::create_dir_all(orphan.parent().unwrap()).unwrap();
+    fs::write(&orphan, b"orphan").unwrap();
+
+    let summary = plan_prune(dir.path(), &options(30)).unwrap();
+    assert_eq!(summary.pruned_entries, 0);
+    assert_eq!(summary.reclaimed_bytes, 6);
+}
+
+#[test]
+fn npm_execute_removes_stale_blobs_and_their_index_lines() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("_cacache");
+    let old = add_npm_entry(&cache, "aa/bb/shared", "old-key", b"old tarball", 90);
+    let new = add_npm_entry(&cache, "aa/bb/shared", "new-key", b"new", 2);
+    let gone = add_npm_entry(&cache, "cc/dd/gone", "gone-key", b"gone", 90);
+
+    let summary = execute_prune(dir.path(), &options(30)).unwrap();
+    assert_eq!(summary.pruned_entries, 2);
+    assert_eq!(summary.reclaimed_bytes, 15);
+    assert!(!old.exists());
+    assert!(!gone.exists());
+    assert!(new.exists());
+    assert_eq!(bucket_keys(&cache, "aa/bb/shared"), vec!["new-key"]);
+    assert!(!cache.join("index-v5/cc/dd/gone").exists());
+
+    let again = plan_prune(dir.path(), &options(30)).unwrap();
+    assert_eq!(again.entry_count, 1);
+    assert_eq!(again.reclaimed_bytes, 0);
+}
+
+#[test]
+fn delete_plan_offers_pruning_only_for_caches() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("npm-cache");
+    add_npm_entry(
+        &cache.join("_cacache"),
+        "aa/bb/old",
+        "old-key",
+        b"old tarball",
+        90,
+    );
+    // A project folder that happens to contain a cacache layout is deleted
+    // whole, never pruned.
+    let project = dir.path().join("project");
+    add_npm_entry(
+        &project.join("_cacache"),
+        "aa/bb/old",
+        "old-key",
+        b"old tarball",
+        90,
+    );
+
+    let entries = [
+        DeleteEntry {
+            path: cache.clone(),
+            is_cache: true,
+        },
+        DeleteEntry {
+            path: project.clone(),
+            is_cache: false,
+        },
+    ];
+    let plan = build_delete_plan(&entries, false, Some(&options(30)), 1);
+    let prune_bytes = |path: &Path| {
+        plan.items
+            .iter()
+            .find(|item| Path::new(&item.path) == path)
+            .unwrap()
+            .prune_bytes
+    };
+    assert_eq!(prune_bytes(&cache), Some(11));
+    assert_eq!(prune_bytes(&project), None);
+}
+
+// Adds a package to a pnpm store. v3 keeps indexes among the content as
+// files/xx/<hash>-index.json; v10 keeps them under index/ and names them
+// after the package, so the index itself omits name and version here.
+fn add_pnpm_package(store: &Path, name: &str, version: &str, data: &[u8]) -> PathBuf {
+    let (integrity, hex) = integrity(data);
+    let content = store.join("files").join(&hex[..2]).join(&hex[2..]);
+    fs::create_dir_all(content.parent().unwrap()).unwrap();
+    fs::write(&content, data).unwrap();
+
+    let files = serde_json::json!({
+        "index.js": { "integrity": integrity, "mode": 420, "size": data.len() }
+    });
+    let index_hex = hex::encode(Sha512::digest(format!("{name}@{version}").as_bytes()));
+    let (index, body) = if store.ends_with("v10") {
+        let file_name = format!(
+            "{}-{}@{version}.json",
+            &index_hex[2..],
+            name.replace('/', "+")
+        );
+        (
+            store.join("index").join(&index_hex[..2]).join(file_name),
+            serde_json::json!({ "files": files }),
+        )
+    } else {
+        (
+            store
+                .join("files")
+                .join(&index_hex[..2])
+                .join(format!("{}-index.json", &index_hex[2..])),
+            serde_json::json!({ "name": name, "version": version, "files": files }),
+        )
+    };
+    fs::create_dir_all(index.parent().unwrap()).unwrap();
+    fs::write(index, body.to_string()).unwrap();
+    content
+}
+
+fn pnpm_project(root: &Path, name: &str, packages: &[&str]) -> PathBuf {
+    let project = root.join(name);
+    for package in packages {
+        fs::create_dir_all(project.join("node_modules/.pnpm").join(package)).unwrap();
+    }
+    project
+}
+
+fn pnpm_options(project_paths: Vec<PathBuf>) -> PruneOptions {
+    PruneOptions {
+        project_paths,
+        ..options(0)
+    }
+}
+
+#[test]
+fn pnpm_v3_store_keeps_packages_a_project_references() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("store/v3");
+    let used = add_pnpm_package(&store, "@scope/used", "1.0.0", b"used");
+    let unused = add_pnpm_package(&store, "unused", "2.0.0", b"unused code");
+    let project = pnpm_project(dir.path(), "app", &["@scope+used@1.0.0_react@18.2.0"]);
+
+    let options = pnpm_options(vec![project]);
+    let summary = execute_prune(&dir.path().join("store"), &options).unwrap();
+    assert_eq!(summary.entry_count, 2);
+    assert_eq!(summary.pruned_entries, 1);
+    assert!(used.exists());
+    assert!(!unused.exists());
+
+    let again = plan_prune(&dir.path().join("store"), &options).unwrap();
+    assert_eq!(again.entry_count, 1);
+    assert_eq!(again.pruned_entries, 0);
+}
+
+#[test]
+fn pnpm_store_is_not_pruned_without_scanned_projects() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("store/v3");
+    let package = add_pnpm_package(&store, "lodash", "4.17.21", b"lodash");
+
+    assert!(plan_prune(&store, &options(0)).is_none());
+    assert!(execute_prune(&store, &options(0)).is_err());
+    assert!(package.exists());
+}
+
+#[test]
+fn pnpm_plan_keeps_unlinked_packages_a_project_references() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("store/v3");
+    // Copied installs leave every store file with a single link, so only the
+    // project's virtual store says the package is used.
+    let used = add_pnpm_package(&store, "react", "18.2.0", b"react");
+    let unused = add_pnpm_package(&store, "left-pad", "1.3.0", b"left-pad");
+    // An index without name and version can't be matched against projects.
+    let (integrity, hex) = integrity(b"unknown");
+    let unknown_content = store.join("files").join(&hex[..2]).join(&hex[2..]);
+    fs::create_dir_all(unknown_content.parent().unwrap()).unwrap();
+    fs::write(&unknown_content, b"unknown").unwrap();
+    let unknown = store.join("files/00/unknown-index.json");
+    let body = serde_json::json!({
+        "files": { "index.js": { "integrity": integrity, "mode": 420, "size": 7 } }
+    });
+    fs::create_dir_all(unknown.parent().unwrap()).unwrap();
+    fs::write(&unknown, body.to_string()).unwrap();
+    let project = pnpm_project(dir.path(), "app", &["react@18.2.0"]);
+
+    let summary = execute_prune(&store, &pnpm_options(vec![project])).unwrap();
+    assert_eq!(summary.entry_count, 3);
+    assert_eq!(summary.pruned_entries, 1);
+    assert!(used.exists());
+    assert!(!unused.exists());
+    assert!(unknown.exists());
+    assert!(unknown_content.exists());
+}
+
+#[test]
+fn pnpm_v10_store_reads_package_ids_from_index_names() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("store/v10");
+    let used = add_pnpm_package(&store, "@scope/used", "1.0.0", b"used");
+    let unused = add_pnpm_package(&store, "unused", "2.0.0", b"unused code");
+    let project = pnpm_project(dir.path(), "app", &["@scope+used@1.0.0"]);
+
+    let options = pnpm_options(vec![project]);
+    let summary = plan_prune(&store, &options).unwrap();
+    assert_eq!(summary.entry_count, 2);
+    assert_eq!(summary.pruned_entries, 1);
+
+    execute_prune(&store, &options).unwrap();
+    assert!(used.exists());
+    assert!(!unused.exists());
+    let indexes: Vec<String> = walkdir::WalkDir::new(store.join("index"))
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(indexes.len(), 1);
+    assert!(indexes[0].ends_with("-@scope+used@1.0.0.json"));
+}
+
+#[test]
+fn folder_with_a_bare_files_dir_is_not_a_pnpm_store() {
+    let dir = TempDir::new().unwrap();
+    let files = dir.path().join("files/ab");
+    fs::create_dir_all(&files).unwrap();
+    fs::write(files.join("cdef"), b"not a store").unwrap();
+
+    assert!(plan_prune(dir.path(), &options(0)).is_none());
+    assert!(plan_prune(&dir.path().join("v10"), &options(0)).is_none());
+}
+
+#[cfg(unix)]
+#[test]
+fn pnpm_plan_ignores_links_held_by_projects_being_deleted() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("store/v3");
+    let content = add_pnpm_package(&store, "linked", "1.0.0", b"linked");
+    let old = pnpm_project(dir.path(), "old", &[]);
+    fs::create_dir_all(old.join("node_modules/linked")).unwrap();
+    fs::hard_link(&content, old.join("node_modules/linked/index.js")).unwrap();
+    let app = pnpm_project(dir.path(), "app", &[]);
+    let projects = vec![old.clone(), app];
+
+    // The link keeps the package alive while its project stays.
+    let kept = plan_prune(&store, &pnpm_options(projects.clone())).unwrap();
+    assert_eq!(kept.pruned_entries, 0);
+
+    // Once the project is part of the deletion, the plan matches what is
+    // left after it is gone, whichever happens first.
+    let entries = [DeleteEntry {
+        path: old.clone(),
+        is_cache: false,
+    }];
+    let options = pnpm_options(projects).excluding(&entries);
+    let before = plan_prune(&store, &options).unwrap();
+    fs::remove_dir_all(&old).unwrap();
+    let after = plan_prune(&store, &options).unwrap();
+    assert_eq!(before.pruned_entries, 1);
+    assert_eq!(before.reclaimed_bytes, after.reclaimed_bytes);
+    assert_eq!(before.pruned_entries, after.pruned_entries);
+}
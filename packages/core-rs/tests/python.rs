@@ -0,0 +1,79 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const PYPROJECT: &str = r#"[project]
+name = "forecast"
+keywords = ["startup"]
+dependencies = ["numpy>=1.26", "Pandas[performance]", "requests"]
+
+[project.optional-dependencies]
+dev = ["pytest", "numpy"]
+
+[dependency-groups]
+lint = ["ruff"]
+"#;
+
+#[test]
+fn python_project_reports_virtualenv_tool_caches_and_bytecode() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("forecast");
+    write(&root, "pyproject.toml", PYPROJECT);
+    write(
+        &root,
+        "requirements-dev.txt",
+        "-r requirements.txt\nblack==24.1\n# mypy\n",
+    );
+    write(&root, ".venv/pyvenv.cfg", "home = /usr/bin\n");
+    write(
+        &root,
+        ".venv/lib/python3.12/site-packages/numpy/__pycache__/x.pyc",
+        "pyc",
+    );
+    write(&root, ".pytest_cache/v/cache/lastfailed", "{}");
+    write(&root, ".mypy_cache/3.12/forecast.json", "{}");
+    write(
+        &root,
+        "src/forecast/__pycache__/model.cpython-312.pyc",
+        "pyc",
+    );
+    write(&root, "tests/__pycache__/test_model.cpython-312.pyc", "pyc");
+    write(&root, "node_modules/pkg/__pycache__/gyp.pyc", "pyc");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    let project = &projects[0];
+    assert_eq!(project.ecosystem, Some(Ecosystem::Python));
+    assert_eq!(project.name, "forecast");
+    assert!(project.has_startup_keyword);
+    // numpy, pandas, requests, pytest, ruff and black.
+    assert_eq!(project.dependency_count, 6);
+    assert_eq!(
+        artifact_paths(project, &root),
+        vec![
+            PathBuf::from(".mypy_cache"),
+            PathBuf::from(".pytest_cache"),
+            PathBuf::from(".venv"),
+            PathBuf::from("src/forecast/__pycache__"),
+            PathBuf::from("tests/__pycache__"),
+        ]
+    );
+}
+
+#[test]
+fn venv_folder_without_pyvenv_cfg_is_not_offered() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("legacy");
+    write(&root, "requirements.txt", "flask==3.0\n");
+    // A hand-made folder that happens to be called venv holds sources.
+    write(&root, "venv/notes.py", "print('keep me')");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "legacy");
+    assert_eq!(projects[0].dependency_count, 1);
+    assert!(projects[0].artifacts.is_empty());
+}
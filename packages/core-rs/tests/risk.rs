@@ -0,0 +1,46 @@
+use devclean_core::{evaluate_heuristic, CacheKind, ProjectMeta, RiskClass};
+
+fn cache(kind: CacheKind, in_use: Option<bool>) -> ProjectMeta {
+    ProjectMeta {
+        id: "cache".to_string(),
+        path: "/home/user/.cache/tool".to_string(),
+        name: "tool cache".to_string(),
+        ecosystem: None,
+        manifests: Vec::new(),
+        artifacts: Vec::new(),
+        dependency_count: 0,
+        has_git: false,
+        has_env_file: false,
+        has_state_file: false,
+        has_startup_keyword: false,
+        last_modified: 0,
+        last_modified_days: 100,
+        size_bytes: 0,
+        is_cache: true,
+        cache_kind: Some(kind),
+        in_use,
+        parent_id: None,
+    }
+}
+
+#[test]
+fn pinned_caches_are_never_burners() {
+    for kind in [
+        CacheKind::Derived,
+        CacheKind::Download,
+        CacheKind::Toolchain,
+    ] {
+        let risk = evaluate_heuristic(&cache(kind, Some(true)));
+        assert_eq!(risk.class_name, RiskClass::Active, "{kind:?}");
+    }
+}
+
+#[test]
+fn unpinned_derived_caches_are_burners() {
+    let risk = evaluate_heuristic(&cache(CacheKind::Derived, Some(false)));
+    assert_eq!(risk.class_name, RiskClass::Burner);
+    assert_eq!(risk.score, 0);
+
+    let risk = evaluate_heuristic(&cache(CacheKind::Derived, None));
+    assert_eq!(risk.class_name, RiskClass::Burner);
+}
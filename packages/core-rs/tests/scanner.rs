@@ -0,0 +1,74 @@
+mod common;
+
+use common::write;
+use devclean_core::{scan_projects, ScanProgress};
+use std::path::Path;
+use tempfile::TempDir;
+
+fn scan_json(root: &Path, threads: usize) -> Vec<serde_json::Value> {
+    let mut projects =
+        scan_projects(root, false, false, threads, None::<fn(ScanProgress)>).projects;
+    projects.sort_by(|a, b| a.path.cmp(&b.path));
+    projects
+        .iter()
+        .map(|project| serde_json::to_value(project).unwrap())
+        .collect()
+}
+
+#[test]
+fn scan_results_do_not_depend_on_the_thread_count() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "web/package.json",
+        r#"{ "name": "web", "dependencies": { "react": "18" } }"#,
+    );
+    write(
+        root,
+        "web/node_modules/react/index.js",
+        "module.exports = {}",
+    );
+    write(root, "web/.env", "TOKEN=1");
+    write(root, "web/dist/app.js", "bundle");
+    write(
+        root,
+        "api/Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    );
+    write(
+        root,
+        "api/crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\n",
+    );
+    write(root, "api/crates/core/src/lib.rs", "");
+    write(root, "api/target/debug/core", "binary");
+    write(root, "ml/pyproject.toml", "[project]\nname = \"ml\"\n");
+    write(root, "ml/.venv/lib/site.py", "venv");
+    write(root, "ml/src/ml/__pycache__/model.pyc", "pyc");
+    write(root, "engine/CMakeLists.txt", "project(engine)\n");
+    write(root, "engine/build/CMakeCache.txt", "cache");
+    write(
+        root,
+        "infra/main.tf",
+        "resource \"null_resource\" \"a\" {}\n",
+    );
+    write(root, "infra/.terraform/providers/null/provider", "provider");
+    write(root, "infra/.terraform/terraform.tfstate", "{}");
+    for index in 0..20 {
+        write(
+            root,
+            &format!("many/app{index}/package.json"),
+            &format!(r#"{{ "name": "app{index}" }}"#),
+        );
+        write(
+            root,
+            &format!("many/app{index}/node_modules/x/index.js"),
+            "x",
+        );
+    }
+
+    let single = scan_json(root, 1);
+    assert_eq!(single.len(), 25);
+    assert_eq!(single, scan_json(root, 8));
+}
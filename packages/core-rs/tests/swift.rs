@@ -0,0 +1,86 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const PACKAGE: &str = r#"// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "Networking",
+    dependencies: [
+        .package(url: "https://github.com/apple/swift-nio.git", from: "2.0.0"),
+        .package(url: "https://github.com/apple/swift-log.git", from: "1.5.0"),
+    ],
+    targets: [.target(name: "Networking")]
+)
+"#;
+
+#[test]
+fn swift_package_reports_its_build_folder() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("networking");
+    write(&root, "Package.swift", PACKAGE);
+    write(&root, "Sources/Networking/Client.swift", "struct Client {}");
+    write(&root, ".build/debug/Networking.swiftmodule", "module");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Swift));
+    assert_eq!(projects[0].name, "Networking");
+    assert_eq!(projects[0].dependency_count, 2);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from(".build")]
+    );
+    assert_eq!(projects[0].artifacts[0].size_bytes, 6);
+}
+
+#[test]
+fn cocoapods_and_carthage_report_their_dependency_folders() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("App");
+    write(
+        &root,
+        "Podfile",
+        "platform :ios, '15.0'\ntarget 'App' do\n  pod 'Alamofire'\n  pod 'SnapKit'\nend\n",
+    );
+    write(&root, "Podfile.lock", "PODS:\n  - Alamofire (5.8.0)\n");
+    write(&root, "Pods/Alamofire/Source/AF.swift", "enum AF {}");
+    write(
+        &root,
+        "Cartfile",
+        "github \"ReactiveX/RxSwift\" ~> 6.0\nbinary \"https://example.com/sdk.json\"\n",
+    );
+    // Carthage/Checkouts holds sources; only Carthage/Build is regenerable.
+    write(&root, "Carthage/Checkouts/RxSwift/README.md", "readme");
+    write(
+        &root,
+        "Carthage/Build/RxSwift.xcframework/Info.plist",
+        "plist",
+    );
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Swift));
+    assert_eq!(projects[0].name, "App");
+    assert_eq!(projects[0].dependency_count, 4);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![PathBuf::from("Carthage/Build"), PathBuf::from("Pods")]
+    );
+}
+
+#[test]
+fn build_folder_without_its_manifest_is_left_alone() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("App");
+    write(&root, "Podfile", "pod 'Alamofire'\n");
+    write(&root, ".build/debug/leftover", "old");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].artifacts.is_empty());
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn unity_project_reports_its_generated_folders() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Runner");
+    write(
+        &root,
+        "ProjectSettings/ProjectVersion.txt",
+        "m_EditorVersion: 2022.3.10f1\n",
+    );
+    write(
+        &root,
+        "ProjectSettings/ProjectSettings.asset",
+        "PlayerSettings:\n  productName: 'Endless Runner'\n",
+    );
+    write(
+        &root,
+        "Packages/manifest.json",
+        r#"{ "dependencies": { "com.unity.inputsystem": "1.7.0", "com.unity.ugui": "1.0.0" } }"#,
+    );
+    write(&root, "Assets/Scripts/Player.cs", "class Player {}");
+    write(&root, "Library/ArtifactDB", "0123456789");
+    write(&root, "Temp/UnityLockfile", "lock");
+    write(&root, "Logs/AssetImportWorker0.log", "log");
+    write(&root, ".git/HEAD", "ref: refs/heads/main\n");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    let project = &projects[0];
+    assert_eq!(project.ecosystem, Some(Ecosystem::Unity));
+    assert_eq!(project.name, "Endless Runner");
+    assert_eq!(project.dependency_count, 2);
+    assert!(project.has_git);
+    assert_eq!(
+        artifact_paths(project, &root),
+        vec![
+            PathBuf::from("Library"),
+            PathBuf::from("Logs"),
+            PathBuf::from("Temp"),
+        ]
+    );
+    // Folders walked before ProjectSettings turned up still count.
+    let bytes: u64 = walkdir::WalkDir::new(&root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.metadata().unwrap().len())
+        .sum();
+    assert_eq!(project.size_bytes, bytes);
+}
+
+#[test]
+fn project_settings_without_assets_is_not_a_unity_project() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Backup");
+    write(
+        &root,
+        "ProjectSettings/ProjectVersion.txt",
+        "m_EditorVersion: 2022.3.10f1\n",
+    );
+    write(&root, "Library/ArtifactDB", "db");
+
+    assert!(scan(dir.path()).is_empty());
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{artifact_paths, scan, write};
+use devclean_core::Ecosystem;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const UPROJECT: &str = r#"{
+    "FileVersion": 3,
+    "EngineAssociation": "5.3",
+    "Plugins": [
+        { "Name": "Built", "Enabled": true },
+        { "Name": "Market", "Enabled": true },
+        { "Name": "Disabled", "Enabled": false }
+    ]
+}"#;
+
+#[test]
+fn project_reports_build_folders_of_plugins_with_source() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("Game");
+    write(&root, "Game.uproject", UPROJECT);
+    write(&root, "Source/Game/Game.cpp", "// game");
+    write(&root, "Intermediate/Build/Game.obj", "obj");
+    write(&root, "Saved/Logs/Game.log", "log");
+    write(&root, "Plugins/Built/Source/Built/Built.cpp", "// built");
+    write(&root, "Plugins/Built/Binaries/Win64/Built.dll", "dll");
+    write(&root, "Plugins/Built/Intermediate/Build/Built.obj", "obj");
+    // A marketplace plugin only ships its binaries; they can't be rebuilt.
+    write(&root, "Plugins/Market/Market.uplugin", "{}");
+    write(&root, "Plugins/Market/Binaries/Win64/Market.dll", "dll");
+
+    let projects = scan(dir.path());
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].ecosystem, Some(Ecosystem::Unreal));
+    assert_eq!(projects[0].name, "Game");
+    assert_eq!(projects[0].dependency_count, 2);
+    assert_eq!(
+        artifact_paths(&projects[0], &root),
+        vec![
+            PathBuf::from("Intermediate"),
+            PathBuf::from("Plugins/Built/Binaries"),
+            PathBuf::from("Plugins/Built/Intermediate"),
+            PathBuf::from("Saved"),
+        ]
+    );
+}
@@ -9,19 +9,51 @@ export type RiskAssessment = {
 	source: RiskSource;
 };
 
+export type Ecosystem =
+	| 'Node'
+	| 'Rust'
+	| 'Python'
+	| 'Jvm'
+	| 'Go'
+	| 'Dotnet'
+	| 'Swift'
+	| 'Dart'
+	| 'Php'
+	| 'Ruby'
+	| 'Elixir'
+	| 'Haskell'
+	| 'Infra'
+	| 'Cpp'
+	| 'Unity'
+	| 'Unreal';
+
+export type CacheKind = 'Derived' | 'Download' | 'Toolchain';
+
+export type ProjectArtifact = {
+	path: string;
+	sizeBytes: number;
+	outsideProject: boolean;
+};
+
 export type ProjectMeta = {
 	id: string;
 	path: string;
 	name: string;
-	packageJsonPath: string;
+	ecosystem?: Ecosystem;
+	manifests: string[];
+	artifacts: ProjectArtifact[];
 	dependencyCount: number;
 	hasGit: boolean;
 	hasEnvFile: boolean;
+	hasStateFile: boolean;
 	hasStartupKeyword: boolean;
 	lastModified: number;
 	lastModifiedDays: number;
 	sizeBytes: number;
 	isCache: boolean;
+	cacheKind?: CacheKind;
+	inUse?: boolean;
+	parentId?: string;
 };
 
 export type ProjectRecord = ProjectMeta & {
@@ -47,6 +79,7 @@ export type ScanRequest = {
 	scanAll: boolean;
 	aiEnabled: boolean;
 	scanCaches: boolean;
+	threads?: number;
 };
 
 export type ScanResponse = {
@@ -71,11 +104,20 @@ export type DeleteRequest = {
 	depsOnly: boolean;
 	dryRun: boolean;
 	quarantine: boolean;
+	threads?: number;
+	prune?: PruneOptions;
+};
+
+export type PruneOptions = {
+	maxAgeDays: number;
+	projectPaths?: string[];
 };
 
 export type DeleteItem = {
 	path: string;
+	projectPath: string;
 	sizeBytes: number;
+	pruneBytes?: number;
 	action: string;
 	status: string;
 	destination?: string;